@@ -0,0 +1,44 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+use toml::Table;
+
+/// Generates a test for every day in `answers.toml` that has at least one
+/// known answer. The tests are included by `tests/verify.rs`.
+fn main() {
+    println!("cargo:rerun-if-changed=answers.toml");
+
+    let answers: Table = fs::read_to_string("answers.toml")
+        .expect("Failed to read answers.toml")
+        .parse()
+        .expect("Failed to parse answers.toml");
+
+    let mut tests = String::new();
+
+    for (year, days) in &answers {
+        let days = days.as_table().expect("Years in answers.toml are tables");
+
+        for (day, expected) in days {
+            let has_answer = ["part_1", "part_2"]
+                .iter()
+                .any(|part| expected.get(part).is_some());
+
+            if !has_answer {
+                continue;
+            }
+
+            if expected.get("slow").and_then(toml::Value::as_bool) == Some(true) {
+                tests.push_str("#[ignore = \"slow\"]\n");
+            }
+
+            writeln!(
+                tests,
+                "#[test]\nfn y{year}_day_{day}() {{\n    check({year}, \"{day}\");\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    fs::write(Path::new(&out_dir).join("answers.rs"), tests).unwrap();
+}
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+/// The answer to a part. Equality ignores formatting noise such as surrounding
+/// blank lines and trailing whitespace, so an answer can be compared directly
+/// to the text of an expected answer.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of pixels that spell out the answer, as drawn by day 10
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid<T: Into<String>>(rows: impl IntoIterator<Item = T>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    fn normalized(&self) -> String {
+        normalize(&self.to_string())
+    }
+}
+
+/// Drops blank lines around the answer and trailing whitespace on every line.
+/// Leading whitespace only matters for grids, where it is part of the picture.
+fn normalize(s: &str) -> String {
+    let lines: Vec<&str> = s.lines().map(str::trim_end).collect();
+    let start = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(start, |i| i + 1);
+
+    match &lines[start..end] {
+        [line] => line.trim_start().to_string(),
+        lines => lines.join("\n"),
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.normalized() == normalize(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(n as i128)
+            }
+        })*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
@@ -0,0 +1,61 @@
+use std::{collections::BTreeMap, fs, path::Path, str::FromStr};
+
+use anyhow::{Context, Error, Result};
+use serde::Deserialize;
+
+use crate::runner::Part;
+
+/// The known answers of a day. Either part may be missing while it is not
+/// solved yet.
+#[derive(Debug, Default, Deserialize)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    /// Too slow to run on every `cargo test`
+    #[serde(default)]
+    pub slow: bool,
+}
+
+impl Expected {
+    pub fn part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// The expected answers of every day, as kept in `answers.toml` with one table
+/// per year and day, e.g. `[2022.07]`.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<u16, BTreeMap<String, Expected>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        fs::read_to_string(path)
+            .context(format!("Failed to read {}", path.display()))?
+            .parse()
+            .context(format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: &str) -> Option<&Expected> {
+        self.0.get(&year)?.get(day)
+    }
+
+    /// All days of `year` with known answers, in order.
+    pub fn days(&self, year: u16) -> impl Iterator<Item = (&str, &Expected)> {
+        self.0
+            .get(&year)
+            .into_iter()
+            .flatten()
+            .map(|(day, expected)| (day.as_str(), expected))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self(toml::from_str(s)?))
+    }
+}
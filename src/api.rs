@@ -0,0 +1,116 @@
+use std::{env, fs::read_to_string, time::Duration};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{ledger::Verdict, runner::Part};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_SESSION_FILE: &str = "./.session";
+const USER_AGENT: &str = "github.com/jsfr/advent-of-code-2022";
+
+/// Talks to the Advent of Code website, or whatever `AOC_BASE_URL` points at.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Builds a client from `AOC_BASE_URL` and a session token taken from
+    /// `AOC_SESSION`, or else read from the file named by `AOC_SESSION_FILE`
+    /// (`./.session` by default).
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let file = env::var("AOC_SESSION_FILE")
+                    .unwrap_or_else(|_| DEFAULT_SESSION_FILE.to_string());
+
+                read_to_string(&file).context(format!(
+                    "No session token found, set AOC_SESSION or write it to {file}"
+                ))?
+            }
+        };
+
+        if session.trim().is_empty() {
+            bail!("The session token is empty");
+        }
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn download_input(&self, year: u16, day: &str) -> Result<String> {
+        let url = format!("{}/{year}/day/{}/input", self.base_url, day_number(day)?);
+
+        let input = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .context(format!("Failed to download {url}"))?
+            .into_string()
+            .context(format!("Failed to read the response from {url}"))?;
+
+        Ok(input)
+    }
+
+    /// Submits an answer and reads the verdict out of the returned page.
+    pub fn submit_answer(&self, year: u16, day: &str, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day_number(day)?);
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", level), ("answer", answer)])
+            .context(format!("Failed to submit to {url}"))?
+            .into_string()
+            .context(format!("Failed to read the response from {url}"))?;
+
+        parse_verdict(&page)
+    }
+}
+
+fn parse_verdict(page: &str) -> Result<Verdict> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("too high") {
+        Ok(Verdict::TooHigh)
+    } else if page.contains("too low") {
+        Ok(Verdict::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if page.contains("You gave an answer too recently") {
+        bail!("Answer was submitted too recently, wait before trying again")
+    } else if page.contains("You don't seem to be solving the right level") {
+        bail!("This part is either locked or already solved")
+    } else {
+        bail!("Could not find a verdict in the response")
+    }
+}
+
+/// The number of a day such as `07`, checked to be one of the 25 puzzle days.
+pub fn day_number(day: &str) -> Result<u32> {
+    match day.parse() {
+        Ok(n @ 1..=25) => Ok(n),
+        _ => bail!("Day {day} is not between 1 and 25"),
+    }
+}
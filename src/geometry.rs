@@ -0,0 +1,200 @@
+use std::{
+    array,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Position;
+
+/// A point in `N` dimensions, e.g. `Point([x, y, z])` in 3D. In 2D, x grows
+/// to the right and y grows downwards, the way puzzle inputs are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize = 2>(pub [i64; N]);
+
+/// The displacement from one point to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const N: usize = 2>(pub [i64; N]);
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Self = Self([0; N]);
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (other - self).chebyshev()
+    }
+}
+
+impl Point<2> {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self([x, y])
+    }
+
+    pub fn x(self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(self) -> i64 {
+        self.0[1]
+    }
+
+    /// The grid position at row y and column x, unless either is negative.
+    pub fn position(self) -> Option<Position> {
+        Some((self.y().try_into().ok()?, self.x().try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point<2> {
+    fn from((row, column): Position) -> Self {
+        Self::new(column as i64, row as i64)
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The length of the vector walking along the axes.
+    pub fn manhattan(self) -> i64 {
+        self.0.iter().map(|d| d.abs()).sum()
+    }
+
+    /// The length of the vector when diagonal steps count as one.
+    pub fn chebyshev(self) -> i64 {
+        self.0.iter().map(|d| d.abs()).max().unwrap_or_default()
+    }
+
+    /// The vector with every component clamped to -1, 0 or 1, i.e. the
+    /// single step that goes the most towards it.
+    pub fn signum(self) -> Self {
+        Self(self.0.map(i64::signum))
+    }
+}
+
+impl Vector<2> {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self([x, y])
+    }
+
+    pub fn x(self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(self) -> i64 {
+        self.0[1]
+    }
+}
+
+impl<const N: usize> Add<Vector<N>> for Point<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector<N>) -> Self {
+        Self(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize> AddAssign<Vector<N>> for Point<N> {
+    fn add_assign(&mut self, rhs: Vector<N>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> Sub<Vector<N>> for Point<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<N>) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> SubAssign<Vector<N>> for Point<N> {
+    fn sub_assign(&mut self, rhs: Vector<N>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Vector<N>;
+
+    fn sub(self, rhs: Self) -> Vector<N> {
+        Vector(array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Sub for Vector<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|d| -d))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self(self.0.map(|d| d * rhs))
+    }
+}
+
+/// One of the four directions on a 2D map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// The step one cell in this direction.
+    pub const fn delta(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(0, -1),
+            Self::Right => Vector::new(1, 0),
+            Self::Down => Vector::new(0, 1),
+            Self::Left => Vector::new(-1, 0),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::grid::{Grid, Position};
+
+/// A directed graph with costs on its edges, known by the edges that leave
+/// each node. Its nodes do not have to be listed up front, so a graph can be
+/// a map of tunnels as well as be implied by a grid.
+pub trait Graph {
+    type Node: Clone + Eq + Hash + Ord;
+
+    /// The nodes one edge away from `node`, with the cost of that edge.
+    fn edges(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, usize)>;
+}
+
+/// Every node leads to the ones it maps to, at a cost of 1.
+impl<N: Clone + Eq + Hash + Ord> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn edges(&self, node: &N) -> impl Iterator<Item = (N, usize)> {
+        self.get(node)
+            .into_iter()
+            .flatten()
+            .map(|next| (next.clone(), 1))
+    }
+}
+
+/// A grid as a graph between cells that are next to each other. `cost` gives
+/// the cost of stepping from one cell onto the other, or `None` if that step
+/// is not allowed.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+}
+
+impl<'a, T, F> GridGraph<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    pub fn new(grid: &'a Grid<T>, cost: F) -> Self {
+        Self { grid, cost }
+    }
+}
+
+impl<T, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    type Node = Position;
+
+    fn edges(&self, &node: &Position) -> impl Iterator<Item = (Position, usize)> {
+        self.grid.neighbours_4(node).filter_map(move |next| {
+            let cost = (self.cost)(&self.grid[node], &self.grid[next])?;
+
+            Some((next, cost))
+        })
+    }
+}
+
+/// The number of edges on the shortest way from any of `starts` to every node
+/// that can be reached, whatever the edges cost.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+
+        for (next, _) in graph.edges(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// The cost of the cheapest way from any of `starts` to every node that can
+/// be reached.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, usize> {
+    let mut costs = HashMap::new();
+    let mut queue: BinaryHeap<_> = starts
+        .into_iter()
+        .map(|start| Reverse((0, start)))
+        .collect();
+
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if costs.contains_key(&node) {
+            continue;
+        }
+
+        for (next, edge) in graph.edges(&node) {
+            if !costs.contains_key(&next) {
+                queue.push(Reverse((cost + edge, next)));
+            }
+        }
+
+        costs.insert(node, cost);
+    }
+
+    costs
+}
+
+/// The cost of the cheapest way from `start` to `goal`, if there is one.
+/// `heuristic` guesses the cost from a node to `goal`, and must never guess
+/// too high for the answer to be the cheapest.
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: &G::Node,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Option<usize> {
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if node == *goal {
+            return Some(cost);
+        }
+
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+
+        for (next, edge) in graph.edges(&node) {
+            let next_cost = cost + edge;
+
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// The cost of the cheapest way between every two of `nodes`, indexed like
+/// `nodes`. Ways only go through `nodes`, and edges to any other node are
+/// left out.
+pub fn floyd_warshall<G: Graph>(graph: &G, nodes: &[G::Node]) -> Vec<Vec<Option<usize>>> {
+    let index: HashMap<&G::Node, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut costs = vec![vec![None; nodes.len()]; nodes.len()];
+
+    for (i, node) in nodes.iter().enumerate() {
+        costs[i][i] = Some(0);
+
+        for (next, edge) in graph.edges(node) {
+            if let Some(&j) = index.get(&next) {
+                costs[i][j] = Some(costs[i][j].map_or(edge, |cost: usize| cost.min(edge)));
+            }
+        }
+    }
+
+    for k in 0..nodes.len() {
+        for i in 0..nodes.len() {
+            for j in 0..nodes.len() {
+                if let (Some(first), Some(second)) = (costs[i][k], costs[k][j]) {
+                    if costs[i][j].is_none_or(|cost| first + second < cost) {
+                        costs[i][j] = Some(first + second);
+                    }
+                }
+            }
+        }
+    }
+
+    costs
+}
+
+/// Splits `nodes` into groups that can be reached from one another, for
+/// graphs whose edges go both ways. The nodes of each group are sorted.
+pub fn connected_components<G: Graph>(graph: &G, nodes: &[G::Node]) -> Vec<Vec<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+
+    for node in nodes {
+        if seen.contains(node) {
+            continue;
+        }
+
+        let mut component: Vec<G::Node> = bfs(graph, [node.clone()]).into_keys().collect();
+
+        component.sort_unstable();
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
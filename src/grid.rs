@@ -0,0 +1,243 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Vector},
+    parse::ParseError,
+};
+
+/// A position in a grid as `(row, column)`, counting from the top left.
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_8: [Vector; 8] = [
+    Vector::new(-1, -1),
+    Vector::new(0, -1),
+    Vector::new(1, -1),
+    Vector::new(1, 0),
+    Vector::new(1, 1),
+    Vector::new(0, 1),
+    Vector::new(-1, 1),
+    Vector::new(-1, 0),
+];
+
+/// A rectangle of cells, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a character map, turning every character into a cell with
+    /// `cell`. Characters it returns `None` for are not `what` the map holds,
+    /// and every row has to be as wide as the first.
+    pub fn parse(
+        s: &str,
+        what: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_rows(s, what, None, cell)
+    }
+
+    /// Parses a character map like `parse`, but whose rows may be of
+    /// different widths. Shorter rows are filled up with `padding`.
+    pub fn parse_ragged(
+        s: &str,
+        what: &str,
+        padding: T,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_rows(s, what, Some(&mut || padding.clone()), cell)
+    }
+
+    fn parse_rows(
+        s: &str,
+        what: &str,
+        mut padding: Option<&mut dyn FnMut() -> T>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = match padding {
+            Some(_) => s.lines().map(|line| line.chars().count()).max(),
+            None => s.lines().next().map(|line| line.chars().count()),
+        }
+        .unwrap_or_default();
+        let mut cells = Vec::with_capacity(width * s.lines().count());
+
+        for line in s.lines() {
+            let row_start = cells.len();
+
+            for (i, c) in line.char_indices() {
+                let span = &line[i..i + c.len_utf8()];
+
+                cells.push(
+                    cell(c)
+                        .ok_or_else(|| ParseError::at(s, span, format!("{c:?} is not {what}")))?,
+                );
+            }
+
+            match &mut padding {
+                Some(padding) => cells.resize_with(row_start + width, padding),
+                None if cells.len() - row_start != width => {
+                    return Err(ParseError::at(
+                        s,
+                        line,
+                        format!("Expected a row of {width} characters"),
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or_default(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position).then(|| {
+            let index = self.index(position);
+
+            &mut self.cells[index]
+        })
+    }
+
+    fn index(&self, (row, column): Position) -> usize {
+        row * self.width + column
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(column < self.width, "column {column} is outside the grid");
+
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    /// The positions next to `position` up, right, down and left of it that
+    /// are inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| self.step(position, direction.delta()))
+    }
+
+    /// The positions next to `position`, diagonals included, that are inside
+    /// the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&step| self.step(position, step))
+    }
+
+    /// The positions from `position` on in steps of `step`, nearest first
+    /// and up to the edge of the grid. `position` itself is left out.
+    pub fn ray(&self, position: Position, step: Vector) -> impl Iterator<Item = Position> + '_ {
+        let mut current = Some(position);
+
+        std::iter::from_fn(move || {
+            current = self.step(current?, step);
+            current
+        })
+    }
+
+    /// The position `step` away from `position`, if it is inside the grid.
+    pub fn step(&self, (row, column): Position, step: Vector) -> Option<Position> {
+        let position = (
+            usize::try_from(row as i64 + step.y()).ok()?,
+            usize::try_from(column as i64 + step.x()).ok()?,
+        );
+
+        self.contains(position).then_some(position)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+
+        let index = self.index(position);
+
+        &mut self.cells[index]
+    }
+}
+
+/// Renders every cell with its own `Display`, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
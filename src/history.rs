@@ -0,0 +1,183 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Timings;
+
+/// The timings of one step of a benchmark, either `parse` or a part.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Step {
+    pub step: String,
+    pub runs: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub max_ms: f64,
+}
+
+impl Step {
+    pub fn new(step: &str, timings: &Timings) -> Self {
+        Self {
+            step: step.to_string(),
+            runs: timings.runs(),
+            min_ms: timings.min().as_secs_f64() * 1000.0,
+            median_ms: timings.median().as_secs_f64() * 1000.0,
+            max_ms: timings.max().as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// A benchmark of a day, along with where it was taken. Timings are only
+/// comparable between runs on the same machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub year: u16,
+    pub day: String,
+    pub commit: String,
+    pub machine: String,
+    /// Set when the run was saved as a named baseline
+    pub baseline: Option<String>,
+    pub recorded_at: u64,
+    pub steps: Vec<Step>,
+}
+
+impl BenchRun {
+    /// A run of `year` and `day` taken now, at the current commit on this
+    /// machine.
+    pub fn new(year: u16, day: &str, baseline: Option<String>, steps: Vec<Step>) -> Self {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            year,
+            day: day.to_string(),
+            commit: commit(),
+            machine: machine(),
+            baseline,
+            recorded_at,
+            steps,
+        }
+    }
+
+    /// Compares the median of every step with the same step in `before`. A
+    /// step regressed when it got more than `threshold` percent slower.
+    pub fn compare(&self, before: &BenchRun, threshold: f64) -> Vec<Change> {
+        self.steps
+            .iter()
+            .filter_map(|step| {
+                let previous = before.steps.iter().find(|s| s.step == step.step)?;
+
+                Some(Change {
+                    step: step.step.clone(),
+                    before_ms: previous.median_ms,
+                    after_ms: step.median_ms,
+                    regressed: step.median_ms > previous.median_ms * (1.0 + threshold / 100.0),
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub step: String,
+    pub before_ms: f64,
+    pub after_ms: f64,
+    pub regressed: bool,
+}
+
+impl Change {
+    /// How much slower the step got, in percent. Negative when it got faster.
+    pub fn percent(&self) -> f64 {
+        if self.before_ms == 0.0 {
+            0.0
+        } else {
+            (self.after_ms / self.before_ms - 1.0) * 100.0
+        }
+    }
+}
+
+/// Every benchmark ever taken, kept in a JSON file so that later runs can be
+/// compared against earlier ones.
+pub struct History {
+    path: PathBuf,
+    runs: Vec<BenchRun>,
+}
+
+impl History {
+    /// Opens the history at `path`, starting an empty one if it does not exist.
+    pub fn open(path: &Path) -> Result<Self> {
+        let runs = if path.exists() {
+            let contents = fs::read_to_string(path)
+                .context(format!("Failed to read bench history {}", path.display()))?;
+
+            serde_json::from_str(&contents)
+                .context(format!("Failed to parse bench history {}", path.display()))?
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            runs,
+        })
+    }
+
+    /// The latest run of the same day as `run` on the same machine, or the
+    /// latest one saved as `baseline` when given.
+    pub fn previous(&self, run: &BenchRun, baseline: Option<&str>) -> Option<&BenchRun> {
+        self.runs
+            .iter()
+            .filter(|r| r.year == run.year && r.day == run.day && r.machine == run.machine)
+            .rfind(|r| baseline.is_none() || r.baseline.as_deref() == baseline)
+    }
+
+    pub fn record(&mut self, run: BenchRun) -> Result<()> {
+        self.runs.push(run);
+
+        let contents = serde_json::to_string_pretty(&self.runs)?;
+
+        fs::write(&self.path, contents).context(format!(
+            "Failed to write bench history {}",
+            self.path.display()
+        ))
+    }
+}
+
+/// The short hash of the checked out commit, marked when there are
+/// uncommitted changes, as those are usually what is being measured.
+fn commit() -> String {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{hash}-dirty")
+        }
+        Some(hash) => hash,
+        None => "unknown".to_string(),
+    }
+}
+
+fn machine() -> String {
+    fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
@@ -0,0 +1,100 @@
+use std::{
+    fs::{self, read_to_string, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::api::Client;
+
+const INPUT_DIR: &str = "./input";
+
+/// Reads the input from `path` if given, and otherwise the day's own input.
+pub fn load(year: u16, day: &str, path: Option<&str>) -> Result<String> {
+    match path {
+        Some(path) => read_from(path),
+        None => read(year, day),
+    }
+}
+
+/// Opens the input like `load`, but for reading it a bit at a time rather
+/// than all at once.
+pub fn open(year: u16, day: &str, path: Option<&str>) -> Result<Box<dyn BufRead>> {
+    let path = match path {
+        Some("-") => return Ok(Box::new(io::stdin().lock())),
+        Some(path) => PathBuf::from(path),
+        None => {
+            if !exists(year, day) {
+                // Downloads and caches it
+                read(year, day)?;
+            }
+
+            self::path(year, day)
+        }
+    };
+    let file = File::open(&path).context(format!("Failed to open {}", path.display()))?;
+
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Where the day's input is kept, e.g. `input/2022/07`.
+pub fn path(year: u16, day: &str) -> PathBuf {
+    Path::new(INPUT_DIR).join(year.to_string()).join(day)
+}
+
+/// Whether the day's input is already in the input directory. The empty
+/// placeholder created by `new` does not count.
+pub fn exists(year: u16, day: &str) -> bool {
+    fs::metadata(path(year, day)).is_ok_and(|m| m.len() > 0)
+}
+
+/// Reads the day's input from the input directory, which holds one directory
+/// per year, e.g. `input/2022/07`. A missing or empty input is downloaded once
+/// and cached there, provided a session token is available.
+pub fn read(year: u16, day: &str) -> Result<String> {
+    read_cached(Path::new(INPUT_DIR), year, day, Client::from_env)
+}
+
+pub fn read_cached(
+    dir: &Path,
+    year: u16,
+    day: &str,
+    client: impl FnOnce() -> Result<Client>,
+) -> Result<String> {
+    let dir = dir.join(year.to_string());
+    let file = dir.join(day);
+
+    if file.exists() {
+        let input = read_to_string(&file).context(format!("Failed to read {}", file.display()))?;
+
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    let client = client().context(format!(
+        "{} is missing or empty and cannot be downloaded",
+        file.display()
+    ))?;
+    let input = client.download_input(year, day)?;
+
+    fs::create_dir_all(&dir).context(format!("Failed to create {}", dir.display()))?;
+    fs::write(&file, &input).context(format!("Failed to write {}", file.display()))?;
+
+    Ok(input)
+}
+
+/// Reads the input from a file, or from stdin if `path` is `-`.
+pub fn read_from(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read stdin")?;
+
+        return Ok(input);
+    }
+
+    read_to_string(path).context(format!("Failed to read {path}"))
+}
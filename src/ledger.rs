@@ -0,0 +1,144 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        };
+
+        f.pad(verdict)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: String,
+    pub part: String,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub submitted_at: u64,
+}
+
+/// What the ledger already knows about an answer before it is submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    New,
+    Solved(String),
+    KnownWrong(Verdict),
+    OutOfBounds(String),
+}
+
+/// Every answer ever submitted, kept in a JSON file so wrong answers are
+/// never sent twice.
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Opens the ledger at `path`, starting an empty one if it does not exist.
+    pub fn open(path: &Path) -> Result<Self> {
+        let submissions = if path.exists() {
+            let contents = fs::read_to_string(path)
+                .context(format!("Failed to read ledger {}", path.display()))?;
+
+            serde_json::from_str(&contents)
+                .context(format!("Failed to parse ledger {}", path.display()))?
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn check(&self, year: u16, day: &str, part: Part, answer: &str) -> Check {
+        let part = part.to_string();
+        let submissions: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .collect();
+
+        if let Some(solved) = submissions.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Check::Solved(solved.answer.clone());
+        }
+
+        if let Some(known) = submissions.iter().find(|s| s.answer == answer) {
+            return Check::KnownWrong(known.verdict);
+        }
+
+        let Ok(value) = answer.parse::<i64>() else {
+            return Check::New;
+        };
+        let bound = |verdict| {
+            submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i64>().ok())
+        };
+
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| value >= *high) {
+            return Check::OutOfBounds(format!("{high} was already too high"));
+        }
+
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|low| value <= *low) {
+            return Check::OutOfBounds(format!("{low} was already too low"));
+        }
+
+        Check::New
+    }
+
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: &str,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<()> {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.submissions.push(Submission {
+            year,
+            day: day.to_string(),
+            part: part.to_string(),
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        });
+
+        let contents = serde_json::to_string_pretty(&self.submissions)?;
+
+        fs::write(&self.path, contents)
+            .context(format!("Failed to write ledger {}", self.path.display()))
+    }
+}
@@ -0,0 +1,40 @@
+pub mod answer;
+pub mod answers;
+pub mod api;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod ledger;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod watch;
+
+mod y2022;
+
+registry::years! {
+    y2022,
+}
+
+use anyhow::Result;
+
+pub use answer::Answer;
+pub use runner::Part;
+pub use solution::{AnySolution, Example, Solution};
+
+/// Solves one part of a day, e.g. `solve(2022, "07", Part::One, &input)`.
+pub fn solve(year: u16, day: &str, part: Part, input: &str) -> Result<Answer> {
+    let solution = registry::get(year, day)?;
+    let input = solution.parse(input)?;
+
+    match part {
+        Part::One => solution.compute_1(input.as_ref()),
+        Part::Two => solution.compute_2(input.as_ref()),
+    }
+}
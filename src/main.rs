@@ -1,78 +1,911 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_13;
-mod day_14;
-mod day_16;
-mod day_22;
-mod solution;
-mod tests;
-
-use std::fs::read_to_string;
-
-use anyhow::{bail, Context};
+use std::{
+    collections::HashMap,
+    env,
+    num::NonZeroUsize,
+    panic,
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_2022::{
+    answer::Answer,
+    answers::{Answers, Expected},
+    api::Client,
+    generate::Rng,
+    history::{BenchRun, History, Step},
+    input,
+    ledger::{Check, Ledger},
+    registry::{self, Entry},
+    report::{self, Format, Record, Report},
+    runner::{self, Differential, Outcome, Part, Status, Timings},
+    scaffold,
+    watch::Watcher,
+};
 use argh::FromArgs;
-use solution::Solution;
+
+const LEDGER: &str = "./ledger.json";
+const HISTORY: &str = "./bench.json";
+const ANSWERS: &str = "./answers.toml";
 
 #[derive(FromArgs)]
-/// AOC 2022
+/// Advent of Code
 struct Args {
+    #[argh(subcommand)]
+    command: Command,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+enum Command {
+    Run(RunArgs),
+    All(AllArgs),
+    Bench(BenchArgs),
+    Submit(SubmitArgs),
+    List(ListArgs),
+    Watch(WatchArgs),
+    New(NewArgs),
+    Verify(VerifyArgs),
+    Generate(GenerateArgs),
+    Diff(DiffArgs),
+}
+
+#[derive(FromArgs)]
+/// Run a single part of a day, also what `<day> <part>` without a command does
+#[argh(subcommand, name = "run")]
+struct RunArgs {
     #[argh(positional)]
     day: String,
 
     #[argh(positional)]
     part: String,
+
+    /// read the puzzle input from this path instead, or `-` for stdin
+    #[argh(option)]
+    input: Option<String>,
+
+    /// check the answers for the puzzle's examples instead
+    #[argh(switch)]
+    example: bool,
+
+    /// output format, text or json
+    #[argh(option, default = "Format::Text")]
+    format: Format,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+
+    /// solve while reading the input instead of reading all of it first
+    #[argh(switch)]
+    stream: bool,
+}
+
+#[derive(FromArgs)]
+/// Run both parts of every day and print a summary table
+#[argh(subcommand, name = "all")]
+struct AllArgs {
+    /// check the answers for the puzzle's examples instead
+    #[argh(switch)]
+    example: bool,
+
+    /// output format, text or json
+    #[argh(option, default = "Format::Text")]
+    format: Format,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+
+    /// days to run at the same time, the number of CPUs by default
+    #[argh(option)]
+    jobs: Option<usize>,
 }
 
-fn main() -> anyhow::Result<()> {
-    let args: Args = argh::from_env();
-    let Args { day, part } = args;
+#[derive(FromArgs)]
+/// Time the parts of a day over repeated runs and add them to the bench
+/// history
+#[argh(subcommand, name = "bench")]
+struct BenchArgs {
+    #[argh(positional)]
+    day: String,
+
+    /// only benchmark this part
+    #[argh(positional)]
+    part: Option<String>,
+
+    /// discarded runs before measuring
+    #[argh(option, default = "1")]
+    warmup: usize,
+
+    /// measured runs
+    #[argh(option, default = "10")]
+    runs: usize,
+
+    /// read the puzzle input from this path instead, or `-` for stdin
+    #[argh(option)]
+    input: Option<String>,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
 
-    let file = format!("./input/{}", day.as_str());
-    let input = read_to_string(&file).context(format!("Failed to read {file}"))?;
+    /// compare with the previous run on this machine and fail on regressions
+    #[argh(switch)]
+    compare: bool,
+
+    /// compare with the latest run saved under this name instead
+    #[argh(option)]
+    baseline: Option<String>,
+
+    /// save this run as a baseline under this name
+    #[argh(option)]
+    save: Option<String>,
+
+    /// how many percent slower a step may get before it is a regression
+    #[argh(option, default = "10.0")]
+    threshold: f64,
+}
 
-    if let Some(day) = get_day(day.as_str()) {
-        let answer = match part.as_str() {
-            "01" => day.compute_1(input.as_str())?,
-            "02" => day.compute_2(input.as_str())?,
-            _ => bail!("Part {part} was not found"),
+#[derive(FromArgs)]
+/// Submit the answer to a part and record the verdict in the ledger
+#[argh(subcommand, name = "submit")]
+struct SubmitArgs {
+    #[argh(positional)]
+    day: String,
+
+    #[argh(positional)]
+    part: String,
+
+    /// read the puzzle input from this path instead, or `-` for stdin
+    #[argh(option)]
+    input: Option<String>,
+
+    /// submit even if the ledger says the answer is out of bounds
+    #[argh(switch)]
+    force: bool,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+}
+
+#[derive(FromArgs)]
+/// List the days that have a solution
+#[argh(subcommand, name = "list")]
+struct ListArgs {
+    /// only list the days of this year
+    #[argh(option)]
+    year: Option<u16>,
+}
+
+#[derive(FromArgs)]
+/// Re-run a day whenever its source file or input changes
+#[argh(subcommand, name = "watch")]
+struct WatchArgs {
+    #[argh(positional)]
+    day: String,
+
+    /// only run this part
+    #[argh(positional)]
+    part: Option<String>,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+
+    /// build and run in release mode
+    #[argh(switch)]
+    release: bool,
+
+    /// milliseconds between checks for changes
+    #[argh(option, default = "500")]
+    interval: u64,
+}
+
+#[derive(FromArgs)]
+/// Create and register a new, unsolved day
+#[argh(subcommand, name = "new")]
+struct NewArgs {
+    #[argh(positional)]
+    day: String,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+}
+
+#[derive(FromArgs)]
+/// Check every day against the known answers in answers.toml
+#[argh(subcommand, name = "verify")]
+struct VerifyArgs {
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+
+    /// output format, text or json
+    #[argh(option, default = "Format::Text")]
+    format: Format,
+
+    /// days to run at the same time, the number of CPUs by default
+    #[argh(option)]
+    jobs: Option<usize>,
+}
+
+#[derive(FromArgs)]
+/// Print a random input for a day
+#[argh(subcommand, name = "generate")]
+struct GenerateArgs {
+    #[argh(positional)]
+    day: String,
+
+    /// roughly how many lines or items the input has
+    #[argh(option, default = "100")]
+    size: usize,
+
+    /// seed to reproduce an earlier input, a new one by default
+    #[argh(option)]
+    seed: Option<u64>,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+}
+
+#[derive(FromArgs)]
+/// Check a day against its reference implementations on generated inputs
+#[argh(subcommand, name = "diff")]
+struct DiffArgs {
+    #[argh(positional)]
+    day: String,
+
+    /// how many inputs to try
+    #[argh(option, default = "100")]
+    runs: usize,
+
+    /// size of the largest input, as for generate
+    #[argh(option, default = "20")]
+    size: usize,
+
+    /// seed of the first input, a new one by default
+    #[argh(option)]
+    seed: Option<u64>,
+
+    /// the puzzle year, the most recent one by default
+    #[argh(option)]
+    year: Option<u16>,
+}
+
+fn main() -> Result<()> {
+    let args = parse_args();
+
+    match args.command {
+        Command::Run(args) => run(args),
+        Command::All(args) => run_all(args),
+        Command::Bench(args) => bench(args),
+        Command::Submit(args) => submit(args),
+        Command::List(args) => list(args),
+        Command::Watch(args) => watch(args),
+        Command::New(args) => new(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::Diff(args) => diff(args),
+    }
+}
+
+/// Parses the command line like `argh::from_env`, except that a day given
+/// without a command is run, so that `aoc-2022 07 1` still works as before
+/// there were commands.
+fn parse_args() -> Args {
+    let mut strings: Vec<String> = env::args().collect();
+
+    if strings
+        .get(1)
+        .is_some_and(|first| first.parse::<u8>().is_ok())
+    {
+        strings.insert(1, "run".to_string());
+    }
+
+    let name = Path::new(&strings[0])
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(&strings[0]);
+    let strs: Vec<&str> = strings.iter().map(String::as_str).collect();
+
+    Args::from_args(&[name], &strs[1..]).unwrap_or_else(|early_exit| {
+        process::exit(match early_exit.status {
+            Ok(()) => {
+                println!("{}", early_exit.output);
+                0
+            }
+            Err(()) => {
+                eprintln!(
+                    "{}\nRun {name} --help for more information.",
+                    early_exit.output
+                );
+                1
+            }
+        })
+    })
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let RunArgs {
+        day,
+        part,
+        input,
+        example,
+        format,
+        year,
+        stream,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+    let part: Part = part.parse()?;
+
+    if example {
+        return run_examples(year, day.as_str(), part, format);
+    }
+
+    let solution = registry::get(year, day.as_str())?;
+    let outcome = if stream {
+        let mut input = input::open(year, day.as_str(), input.as_deref())?;
+
+        runner::run_streamed(solution, part, &mut input)
+    } else {
+        let input = input::load(year, day.as_str(), input.as_deref())?;
+
+        runner::run_part(solution, part, &input)
+    };
+
+    if format == Format::Json {
+        let report = Report {
+            year,
+            day: &day,
+            part,
+            example: None,
+            outcome: &outcome,
         };
 
-        println!("The answer is:\n{answer}");
+        return report::print(format, &report);
+    }
+
+    match outcome.status {
+        Status::Ok(answer) => println!("The answer is:\n{answer}"),
+        Status::Error(err) => return Err(err),
+        Status::Unimplemented => bail!("Part {part} is not implemented"),
+    }
+
+    Ok(())
+}
+
+fn run_examples(year: u16, day: &str, part: Part, format: Format) -> Result<()> {
+    let solution = registry::get(year, day)?;
+    let mut checked = 0;
+
+    for (i, example) in solution.examples().iter().enumerate() {
+        let Some(outcome) = runner::run_example(solution, part, example) else {
+            continue;
+        };
+
+        checked += 1;
+
+        if format == Format::Json {
+            let report = Report {
+                year,
+                day,
+                part,
+                example: Some(i + 1),
+                outcome: &outcome,
+            };
+
+            report::print(format, &report)?;
+            continue;
+        }
+
+        match outcome.status {
+            Status::Ok(answer) => println!("Example {} is correct:\n{answer}", i + 1),
+            Status::Error(err) => return Err(err.context(format!("Example {} failed", i + 1))),
+            Status::Unimplemented => bail!("Part {part} is not implemented"),
+        }
+    }
+
+    if checked == 0 {
+        bail!("Day {day} has no examples for part {part}");
+    }
+
+    Ok(())
+}
+
+fn run_all(args: AllArgs) -> Result<()> {
+    // Panics are reported in the table, so keep the default hook from
+    // interleaving its own messages with the rows.
+    panic::set_hook(Box::new(|_| {}));
+
+    let year = args.year.unwrap_or_else(registry::latest);
+    let entries = registry::all(year)?;
+    let jobs = args.jobs.unwrap_or_else(default_jobs);
+
+    report::print_header(args.format);
+
+    let mut failures = 0;
+
+    // Days run in parallel, but their rows are still printed in day order
+    runner::run_ordered(
+        entries,
+        jobs,
+        |entry| (entry.day(), day_outcomes(year, entry, args.example)),
+        |(day, outcomes)| {
+            for (part, example, outcome) in outcomes {
+                if matches!(outcome.status, Status::Error(_)) {
+                    failures += 1;
+                }
+
+                let report = Report {
+                    year,
+                    day,
+                    part,
+                    example,
+                    outcome: &outcome,
+                };
+
+                report::print(args.format, &report)?;
+            }
+
+            Ok(())
+        },
+    )?;
+
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+
+    Ok(())
+}
+
+/// Runs both parts of a day against its input, or against each of its
+/// examples.
+fn day_outcomes(year: u16, entry: &Entry, example: bool) -> Vec<(Part, Option<usize>, Outcome)> {
+    let (day, solution) = (entry.day(), entry.solution);
+
+    if example {
+        return Part::ALL
+            .into_iter()
+            .flat_map(|part| {
+                solution
+                    .examples()
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(i, example)| {
+                        let outcome = runner::run_example(solution, part, example)?;
+
+                        Some((part, Some(i + 1), outcome))
+                    })
+            })
+            .collect();
+    }
+
+    let outcomes = match input::read(year, day) {
+        Ok(input) => runner::run_parts(solution, &Part::ALL, &input),
+        Err(err) => Part::ALL
+            .iter()
+            .map(|_| Outcome::failed(anyhow::anyhow!("{err:#}")))
+            .collect(),
+    };
+
+    Part::ALL
+        .into_iter()
+        .zip(outcomes)
+        .map(|(part, outcome)| (part, None, outcome))
+        .collect()
+}
+
+fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    panic::set_hook(Box::new(|_| {}));
+
+    let year = args.year.unwrap_or_else(registry::latest);
+    let answers = Answers::load(Path::new(ANSWERS))?;
+    let days: Vec<(&str, &Expected)> = answers.days(year).collect();
+    let jobs = args.jobs.unwrap_or_else(default_jobs);
+
+    report::print_header(args.format);
+
+    let mut failures = 0;
+
+    runner::run_ordered(
+        &days,
+        jobs,
+        |&(day, expected)| (day, verify_day(year, day, expected)),
+        |(day, outcomes)| {
+            for (part, outcome) in outcomes {
+                // Unlike `all`, a part with a known answer has to produce it
+                if !matches!(outcome.status, Status::Ok(_)) {
+                    failures += 1;
+                }
+
+                let report = Report {
+                    year,
+                    day,
+                    part,
+                    example: None,
+                    outcome: &outcome,
+                };
+
+                report::print(args.format, &report)?;
+            }
+
+            Ok(())
+        },
+    )?;
+
+    if failures > 0 {
+        bail!("{failures} part(s) did not match {ANSWERS}");
+    }
+
+    Ok(())
+}
+
+/// Runs the parts of a day that have a known answer and checks them against it.
+fn verify_day(year: u16, day: &str, expected: &Expected) -> Vec<(Part, Outcome)> {
+    let parts: Vec<Part> = Part::ALL
+        .into_iter()
+        .filter(|part| expected.part(*part).is_some())
+        .collect();
+    let outcomes = match registry::get(year, day)
+        .and_then(|solution| Ok((solution, input::read(year, day)?)))
+    {
+        Ok((solution, input)) => runner::run_parts(solution, &parts, &input),
+        Err(err) => parts
+            .iter()
+            .map(|_| Outcome::failed(anyhow::anyhow!("{err:#}")))
+            .collect(),
+    };
+
+    parts
+        .into_iter()
+        .zip(outcomes)
+        .map(|(part, outcome)| {
+            let expected = expected.part(part).unwrap_or_default();
+
+            (part, runner::check_answer(outcome, expected))
+        })
+        .collect()
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let BenchArgs {
+        day,
+        part,
+        warmup,
+        runs,
+        input,
+        year,
+        compare,
+        baseline,
+        save,
+        threshold,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+
+    let solution = registry::get(year, day.as_str())?;
+    let parts = match part {
+        Some(part) => vec![part.parse()?],
+        None => Part::ALL.to_vec(),
+    };
+    let input = input::load(year, day.as_str(), input.as_deref())?;
+
+    println!(
+        "{:<4} {:<5} {:>6} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Runs", "Min", "Median", "Max"
+    );
+
+    let timings = runner::bench_parse(solution, &input, warmup, runs)?;
+    let mut steps = vec![Step::new("parse", &timings)];
+
+    print_timings(&day, "parse", &timings);
+
+    for part in parts {
+        let timings = runner::bench_part(solution, part, &input, warmup, runs)?;
+
+        print_timings(&day, &part.to_string(), &timings);
+        steps.push(Step::new(&part.to_string(), &timings));
+    }
+
+    let mut history = History::open(Path::new(HISTORY))?;
+    let run = BenchRun::new(year, &day, save, steps);
+
+    // Look up what to compare with before this run becomes the previous one
+    let previous = if compare || baseline.is_some() {
+        let previous = history.previous(&run, baseline.as_deref());
+
+        match (previous, &baseline) {
+            (Some(previous), _) => Some(previous.clone()),
+            (None, Some(baseline)) => bail!("No baseline {baseline} for day {day} on this machine"),
+            (None, None) => {
+                println!("\nNo previous run of day {day} on this machine to compare with");
+                None
+            }
+        }
     } else {
-        bail!("Day {day} was not found");
+        None
+    };
+
+    history.record(run.clone())?;
+
+    let Some(previous) = previous else {
+        return Ok(());
+    };
+
+    println!(
+        "\nCompared with {} ({}):",
+        previous.baseline.as_deref().unwrap_or("the previous run"),
+        previous.commit
+    );
+    println!(
+        "{:<5} {:>10} {:>10} {:>8}",
+        "Step", "Before", "After", "Change"
+    );
+
+    let changes = run.compare(&previous, threshold);
+
+    for change in &changes {
+        println!(
+            "{:<5} {:>10} {:>10} {:>7.1}%{}",
+            change.step,
+            format!("{:.2?}", Duration::from_secs_f64(change.before_ms / 1000.0)),
+            format!("{:.2?}", Duration::from_secs_f64(change.after_ms / 1000.0)),
+            change.percent(),
+            if change.regressed { "  regressed" } else { "" },
+        );
+    }
+
+    let regressions = changes.iter().filter(|change| change.regressed).count();
+
+    if regressions > 0 {
+        bail!("{regressions} step(s) got more than {threshold}% slower");
+    }
+
+    Ok(())
+}
+
+fn print_timings(day: &str, step: &str, timings: &Timings) {
+    println!(
+        "{day:<4} {step:<5} {:>6} {:>10} {:>10} {:>10}",
+        timings.runs(),
+        format!("{:.2?}", timings.min()),
+        format!("{:.2?}", timings.median()),
+        format!("{:.2?}", timings.max()),
+    );
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let SubmitArgs {
+        day,
+        part,
+        input,
+        force,
+        year,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+    let part: Part = part.parse()?;
+
+    let solution = registry::get(year, day.as_str())?;
+    let input = input::load(year, day.as_str(), input.as_deref())?;
+    let answer = match runner::run_part(solution, part, &input).status {
+        Status::Ok(answer) => answer,
+        Status::Error(err) => return Err(err),
+        Status::Unimplemented => bail!("Part {part} is not implemented"),
+    };
+
+    if let Answer::Grid(_) = answer {
+        bail!("Grid answers have to be read and submitted by hand:\n{answer}");
+    }
+
+    let answer = answer.to_string();
+
+    let mut ledger = Ledger::open(Path::new(LEDGER))?;
+
+    match ledger.check(year, day.as_str(), part, &answer) {
+        Check::New => {}
+        Check::Solved(correct) => bail!("Part {part} is already solved with {correct}"),
+        Check::KnownWrong(verdict) => bail!("{answer} was already submitted and was {verdict}"),
+        Check::OutOfBounds(reason) if !force => {
+            bail!("Not submitting {answer} as {reason}, use --force to submit anyway")
+        }
+        Check::OutOfBounds(reason) => println!("Warning: {reason}"),
     }
 
+    let verdict = Client::from_env()?.submit_answer(year, day.as_str(), part, &answer)?;
+
+    ledger.record(year, day.as_str(), part, &answer, verdict)?;
+
+    println!("Submitted {answer}, the verdict is: {verdict}");
+
     Ok(())
 }
 
-fn get_day(day: &str) -> Option<Box<dyn Solution>> {
-    let solution: Box<dyn Solution> = match day {
-        "01" => Box::new(day_01::Day {}),
-        "02" => Box::new(day_02::Day {}),
-        "03" => Box::new(day_03::Day {}),
-        "04" => Box::new(day_04::Day {}),
-        "05" => Box::new(day_05::Day {}),
-        "06" => Box::new(day_06::Day {}),
-        "07" => Box::new(day_07::Day {}),
-        "08" => Box::new(day_08::Day {}),
-        "09" => Box::new(day_09::Day {}),
-        "10" => Box::new(day_10::Day {}),
-        "11" => Box::new(day_11::Day {}),
-        "13" => Box::new(day_13::Day {}),
-        "14" => Box::new(day_14::Day {}),
-        "16" => Box::new(day_16::Day {}),
-        "22" => Box::new(day_22::Day {}),
-        _ => return None,
+fn list(args: ListArgs) -> Result<()> {
+    println!("{:<4} {:<4} {:>8}  Input", "Year", "Day", "Examples");
+
+    for year in registry::all_years() {
+        let (year, entries) = (year.year(), year.days);
+
+        if args.year.is_some_and(|only| only != year) {
+            continue;
+        }
+
+        for entry in entries {
+            let day = entry.day();
+            let examples = entry.solution.examples().len();
+            let input = if input::exists(year, day) {
+                "yes"
+            } else {
+                "no"
+            };
+
+            println!("{year:<4} {day:<4} {examples:>8}  {input}");
+        }
+    }
+
+    Ok(())
+}
+
+fn watch(args: WatchArgs) -> Result<()> {
+    let WatchArgs {
+        day,
+        part,
+        year,
+        release,
+        interval,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+    let parts = match part {
+        Some(part) => vec![part.parse()?],
+        None => Part::ALL.to_vec(),
     };
 
-    Some(solution)
+    let source = PathBuf::from(format!("src/y{year}/day_{day}.rs"));
+    let mut watcher = Watcher::new([source, input::path(year, &day)]);
+    let mut previous: HashMap<Part, f64> = HashMap::new();
+
+    loop {
+        println!("Running {year} day {day}");
+
+        for &part in &parts {
+            // The solution has to be rebuilt to pick up changes, so every run
+            // goes through cargo and reads back the JSON report
+            match run_with_cargo(year, &day, part, release) {
+                Ok(record) => {
+                    print_record(&record, previous.get(&part).copied());
+
+                    if record.status == "ok" {
+                        previous.insert(part, record.duration_ms);
+                    }
+                }
+                Err(err) => println!("Part {part} failed: {err:#}"),
+            }
+        }
+
+        watcher.wait(Duration::from_millis(interval));
+    }
+}
+
+fn run_with_cargo(year: u16, day: &str, part: Part, release: bool) -> Result<Record> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = process::Command::new(cargo);
+
+    command.args(["run", "--quiet"]);
+
+    if release {
+        command.arg("--release");
+    }
+
+    let output = command
+        .args(["--", "run", day, &part.to_string()])
+        .args(["--year", &year.to_string(), "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to start cargo")?;
+
+    if !output.status.success() {
+        bail!("cargo exited with {}", output.status);
+    }
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let line = stdout.lines().last().context("No report was printed")?;
+
+    serde_json::from_str(line).context(format!("Failed to read the report {line}"))
+}
+
+/// Prints a part's result along with how much faster or slower it solved
+/// than on the previous run.
+fn print_record(record: &Record, previous: Option<f64>) {
+    let part = &record.part;
+
+    match (record.status.as_str(), &record.answer) {
+        ("ok", Some(answer)) => {
+            let solve = Duration::from_secs_f64(record.duration_ms / 1000.0);
+            let parse = Duration::from_secs_f64(record.parse_ms / 1000.0);
+            let change = previous
+                .map(|previous| format!(", {:+.2}ms", record.duration_ms - previous))
+                .unwrap_or_default();
+            let separator = if answer.contains('\n') { "\n" } else { " " };
+
+            println!(
+                "Part {part} (parse {parse:.2?}, solve {solve:.2?}{change}):{separator}{answer}"
+            );
+        }
+        ("error", _) => println!(
+            "Part {part} failed: {}",
+            record.error.as_deref().unwrap_or_default()
+        ),
+        _ => println!("Part {part} is not implemented"),
+    }
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    let year = args.year.unwrap_or_else(registry::latest);
+
+    for file in scaffold::new_day(Path::new("."), year, &args.day)? {
+        println!("Wrote {}", file.display());
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<()> {
+    let GenerateArgs {
+        day,
+        size,
+        seed,
+        year,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+    let seed = seed.unwrap_or_else(Rng::seed);
+
+    let solution = registry::get(year, &day)?;
+    let input = solution
+        .generate(&mut Rng::new(seed), size)
+        .context(format!("Day {day} of {year} has no input generator"))?;
+
+    // The seed goes to stderr so that the input can be redirected to a file
+    eprintln!("Seed {seed}");
+    println!("{input}");
+
+    Ok(())
+}
+
+fn diff(args: DiffArgs) -> Result<()> {
+    let DiffArgs {
+        day,
+        runs,
+        size,
+        seed,
+        year,
+    } = args;
+    let year = year.unwrap_or_else(registry::latest);
+    let day = registry::day_name(&day)?;
+    let seed = seed.unwrap_or_else(Rng::seed);
+
+    let solution = registry::get(year, &day)?;
+
+    match runner::differential(solution, seed, runs, size)? {
+        Differential::Agreed => {
+            println!("Day {day} agrees with its references on {runs} inputs from seed {seed}")
+        }
+        Differential::Diverged(divergence) => bail!("{divergence}"),
+        Differential::NothingToCompare(reason) => bail!("{reason}"),
+    }
+
+    Ok(())
 }
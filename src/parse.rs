@@ -0,0 +1,269 @@
+use std::{error::Error, fmt::Display, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, not_line_ending, space0},
+    combinator::{all_consuming, map_res, opt, recognize, verify},
+    error::ErrorKind,
+    multi::{separated_list0, separated_list1},
+    sequence::{pair, preceded},
+    IResult,
+};
+
+use crate::grid::Grid;
+
+/// A parse failure that points at the offending part of the input. Its
+/// position is relative to the text that was being parsed, and moves along
+/// when that text turns out to be part of a larger input, so that errors
+/// from parsing a single line still point into the whole puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    offset: usize,
+    len: usize,
+    line: usize,
+    column: usize,
+    text: String,
+}
+
+impl ParseError {
+    /// An error about `span`, which has to be a slice of `source`.
+    pub fn at(source: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = offset_of(source, span).unwrap_or_default();
+
+        Self::new(source, offset, span.len(), message.into())
+    }
+
+    fn new(source: &str, offset: usize, len: usize, message: String) -> Self {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        let text = &source[line_start..line_end];
+
+        Self {
+            message,
+            offset,
+            len,
+            line: source[..line_start].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            text: text.trim_end_matches('\r').to_string(),
+        }
+    }
+
+    /// Moves the error from `part` into `source`, of which `part` is a slice.
+    pub fn within(self, source: &str, part: &str) -> Self {
+        let offset = offset_of(source, part).unwrap_or_default() + self.offset;
+
+        Self::new(source, offset, self.len, self.message)
+    }
+
+    /// Moves the error from a line that was parsed on its own onto `line` of
+    /// the whole input, counting from 1.
+    pub fn on_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error in characters, counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The whole line the error is on.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Renders as the message followed by the offending line with a caret under
+/// the problem:
+///
+/// ```text
+/// "x" is not a number at line 2, column 3
+///   2 | 2-x,6-8
+///     |   ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            message,
+            line,
+            column,
+            text,
+            ..
+        } = self;
+
+        let gutter = line.to_string().len();
+        let rest = text.chars().count().saturating_sub(column - 1);
+        let carets = "^".repeat(self.len.clamp(1, rest.max(1)));
+
+        writeln!(f, "{message} at line {line}, column {column}")?;
+        writeln!(f, "{line:>gutter$} | {text}")?;
+        write!(
+            f,
+            "{:gutter$} | {:>width$}",
+            "",
+            carets,
+            width = column - 1 + carets.len()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+fn offset_of(source: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+
+    (offset + part.len() <= source.len()).then_some(offset)
+}
+
+/// Parses `part`, a slice of `source`, and points any error into `source`.
+pub fn within<T>(source: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    part.parse()
+        .map_err(|err: ParseError| err.within(source, part))
+}
+
+/// Parses every line of `input`.
+pub fn lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input.lines().map(|line| within(input, line)).collect()
+}
+
+/// Reads and parses the lines of `input` one at a time, for inputs too large
+/// to read at once.
+pub fn read_lines<'a, T>(input: &'a mut dyn BufRead) -> impl Iterator<Item = anyhow::Result<T>> + 'a
+where
+    T: FromStr<Err = ParseError>,
+{
+    input.lines().enumerate().map(|(i, line)| {
+        let line = line.context("Failed to read the input")?;
+
+        line.parse()
+            .map_err(|err: ParseError| err.on_line(i + 1).into())
+    })
+}
+
+/// Parses every chunk of `input` between blank lines.
+pub fn chunks<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .split("\n\n")
+        .map(|chunk| within(input, chunk))
+        .collect()
+}
+
+/// Parses `part`, a slice of `source`, as a number.
+pub fn number<T: FromStr>(source: &str, part: &str) -> Result<T, ParseError> {
+    part.parse()
+        .map_err(|_| ParseError::at(source, part, format!("{part:?} is not a number")))
+}
+
+/// Splits `s` once at `separator`, failing with an error over all of `s`.
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::at(s, s, format!("Expected {separator:?}")))
+}
+
+/// Turns the result of a nom parser run over `source` into a `ParseError`
+/// pointing at where the parser gave up.
+pub fn finish<'a, T>(source: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let span = err.input.lines().next().unwrap_or(err.input);
+
+            Err(ParseError::at(
+                source,
+                span,
+                format!("Unexpected {span:?} ({:?} failed)", err.code),
+            ))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            source,
+            &source[source.len()..],
+            "Unexpected end of input",
+        )),
+    }
+}
+
+/// Runs a nom parser over the whole of `s`.
+pub fn all<'a, T>(
+    s: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    finish(s, all_consuming(parser)(s))
+}
+
+/// An unsigned integer, e.g. `42`.
+pub fn unsigned<T: FromStr>(s: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(s)
+}
+
+/// An integer with an optional minus sign, e.g. `-42`.
+pub fn signed<T: FromStr>(s: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(s)
+}
+
+/// Any number of `item`s separated by commas, with or without spaces after
+/// them, e.g. `1,2` or `AA, BB`.
+pub fn comma_list<'a, T>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list0(pair(char(','), space0), item)
+}
+
+/// The value of `key=value`.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    preceded(pair(tag(key), char('=')), value)
+}
+
+/// One or more `block`s separated by blank lines.
+pub fn blocks<'a, T>(
+    block: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// The lines up to the next blank line as a grid of `cell`s, see
+/// `Grid::parse_ragged`. A character for which `cell` returns `None` fails
+/// the whole parse.
+pub fn char_grid<'a, T: Clone>(
+    padding: T,
+    cell: impl Fn(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    let line = verify(not_line_ending, |line: &str| !line.is_empty());
+    let mut rows = recognize(separated_list1(line_ending, line));
+
+    move |s| {
+        let (rest, block) = rows(s)?;
+
+        match Grid::parse_ragged(block, "a cell", padding.clone(), &cell) {
+            Ok(grid) => Ok((rest, grid)),
+            Err(err) => Err(nom::Err::Failure(nom::error::Error::new(
+                &block[err.offset..],
+                ErrorKind::Char,
+            ))),
+        }
+    }
+}
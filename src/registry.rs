@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+
+use crate::{api::day_number, solution::AnySolution};
+
+/// Registers the solved days of a year in `DAYS`. Their modules are declared
+/// next to it with plain `mod` items, which rustfmt can follow.
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        pub(crate) static DAYS: &[$crate::registry::Entry] = &[
+            $($crate::registry::Entry {
+                module: stringify!($module),
+                solution: &$module::Day {},
+            }),*
+        ];
+    };
+}
+
+/// Registers the days of every year in `YEARS`, from the year modules named
+/// like `y2022` that are declared next to it.
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        static YEARS: &[$crate::registry::Year] = &[
+            $($crate::registry::Year {
+                module: stringify!($module),
+                days: $module::DAYS,
+            }),*
+        ];
+    };
+}
+
+pub(crate) use days;
+pub(crate) use years;
+
+pub struct Year {
+    pub module: &'static str,
+    pub days: &'static [Entry],
+}
+
+impl Year {
+    /// The year as it is used on the command line and for input directories.
+    pub fn year(&self) -> u16 {
+        self.module
+            .trim_start_matches('y')
+            .parse()
+            .expect("year modules are named like y2022")
+    }
+}
+
+pub struct Entry {
+    pub module: &'static str,
+    pub solution: &'static dyn AnySolution,
+}
+
+impl Entry {
+    /// The day as it is used on the command line and for input files, e.g. `07`.
+    pub fn day(&self) -> &'static str {
+        self.module.trim_start_matches("day_")
+    }
+}
+
+/// All registered years in order.
+pub fn all_years() -> &'static [Year] {
+    crate::YEARS
+}
+
+/// The most recent registered year, used when no year is given.
+pub fn latest() -> u16 {
+    all_years()
+        .last()
+        .map(Year::year)
+        .expect("no years are registered")
+}
+
+/// All registered days of `year` in order.
+pub fn all(year: u16) -> Result<&'static [Entry]> {
+    all_years()
+        .iter()
+        .find(|y| y.year() == year)
+        .map(|y| y.days)
+        .context(format!("Year {year} was not found"))
+}
+
+/// The day as it is registered, e.g. `06` for `6`, so that days can be given
+/// with or without the leading zero.
+pub fn day_name(day: &str) -> Result<String> {
+    Ok(format!("{:02}", day_number(day)?))
+}
+
+pub fn get(year: u16, day: &str) -> Result<&'static dyn AnySolution> {
+    let day = day_name(day)?;
+
+    all(year)?
+        .iter()
+        .find(|entry| entry.day() == day)
+        .map(|entry| entry.solution)
+        .context(format!("Day {day} of {year} was not found"))
+}
@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{Outcome, Part, Status};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Format {s} is not one of text/json"),
+        }
+    }
+}
+
+/// The result of running one part, optionally against one of the examples.
+pub struct Report<'a> {
+    pub year: u16,
+    pub day: &'a str,
+    pub part: Part,
+    pub example: Option<usize>,
+    pub outcome: &'a Outcome,
+}
+
+/// A report as it is printed with `--format json`, one per line.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: String,
+    pub part: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<usize>,
+    pub status: String,
+    pub answer: Option<String>,
+    pub parse_ms: f64,
+    pub duration_ms: f64,
+    pub error: Option<String>,
+}
+
+impl From<&Report<'_>> for Record {
+    fn from(report: &Report) -> Self {
+        let (status, answer, error) = match &report.outcome.status {
+            Status::Ok(answer) => ("ok", Some(answer.to_string()), None),
+            Status::Error(err) => ("error", None, Some(format!("{err:#}"))),
+            Status::Unimplemented => ("unimplemented", None, None),
+        };
+
+        Self {
+            year: report.year,
+            day: report.day.to_string(),
+            part: report.part.to_string(),
+            example: report.example,
+            status: status.to_string(),
+            answer,
+            parse_ms: report.outcome.parse.as_secs_f64() * 1000.0,
+            duration_ms: report.outcome.solve.as_secs_f64() * 1000.0,
+            error,
+        }
+    }
+}
+
+pub fn print_header(format: Format) {
+    if format == Format::Text {
+        println!(
+            "{:<4} {:<4} {:<14} {:>10} {:>10}  Answer",
+            "Day", "Part", "Status", "Parse", "Solve"
+        );
+    }
+}
+
+/// Prints a report as a table row or as a single line of JSON.
+pub fn print(format: Format, report: &Report) -> Result<()> {
+    match format {
+        Format::Text => print_row(report),
+        Format::Json => println!("{}", serde_json::to_string(&Record::from(report))?),
+    }
+
+    Ok(())
+}
+
+fn print_row(report: &Report) {
+    let Report {
+        day,
+        part,
+        example,
+        outcome,
+        ..
+    } = report;
+
+    let label = match example {
+        Some(i) => format!("{day}#{i}"),
+        None => day.to_string(),
+    };
+    let (status, answer) = match &outcome.status {
+        Status::Ok(answer) => ("ok", answer.to_string()),
+        Status::Error(err) => ("error", format!("{err:#}")),
+        Status::Unimplemented => ("unimplemented", String::new()),
+    };
+    let parse = format!("{:.2?}", outcome.parse);
+    let solve = format!("{:.2?}", outcome.solve);
+    let row = format!("{label:<4} {part:<4} {status:<14} {parse:>10} {solve:>10}");
+
+    // Multi-line answers (day 10's CRT) are printed underneath their row
+    if answer.contains('\n') {
+        println!("{row}\n{answer}");
+    } else {
+        println!("{}", format!("{row}  {answer}").trim_end());
+    }
+}
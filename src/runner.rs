@@ -0,0 +1,482 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt::Display,
+    io::BufRead,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    solution::{AnySolution, Example},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" | "01" => Ok(Part::One),
+            "2" | "02" => Ok(Part::Two),
+            _ => bail!("Part {s} was not found"),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let part = match self {
+            Part::One => "01",
+            Part::Two => "02",
+        };
+
+        f.pad(part)
+    }
+}
+
+#[derive(Debug)]
+pub enum Status {
+    Ok(Answer),
+    Error(Error),
+    Unimplemented,
+}
+
+/// How a part went. Parsing is shared by both parts, so `parse` is the time it
+/// took to parse the input once and `solve` only covers the part itself.
+#[derive(Debug)]
+pub struct Outcome {
+    pub status: Status,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Outcome {
+    pub fn failed(err: Error) -> Self {
+        Self {
+            status: Status::Error(err),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+        }
+    }
+}
+
+/// Runs a single part, turning panics into a status so one broken day does not
+/// take down a whole run. `todo!()` and `unimplemented!()` are reported as
+/// unimplemented rather than as errors.
+pub fn run_part(solution: &dyn AnySolution, part: Part, input: &str) -> Outcome {
+    let mut outcomes = run_parts(solution, &[part], input);
+
+    outcomes.remove(0)
+}
+
+/// Parses the input once and runs each of `parts` against it. If parsing fails
+/// every part fails with the same error.
+pub fn run_parts(solution: &dyn AnySolution, parts: &[Part], input: &str) -> Vec<Outcome> {
+    let (parsed, parse) = guarded(|| solution.parse(input));
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(status) => {
+            return parts
+                .iter()
+                .map(|_| Outcome {
+                    status: status.duplicate(),
+                    parse,
+                    solve: Duration::ZERO,
+                })
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let (result, solve) = guarded(|| solve(solution, part, parsed.as_ref()));
+            let status = match result {
+                Ok(answer) => Status::Ok(answer),
+                Err(status) => status,
+            };
+
+            Outcome {
+                status,
+                parse,
+                solve,
+            }
+        })
+        .collect()
+}
+
+/// Runs a single part while reading the input, for days that can. Parsing
+/// and solving happen together, so all of the time counts as solving.
+pub fn run_streamed(solution: &dyn AnySolution, part: Part, input: &mut dyn BufRead) -> Outcome {
+    let (result, solve) = guarded(|| {
+        let answer = match part {
+            Part::One => solution.stream_1(input),
+            Part::Two => solution.stream_2(input),
+        };
+
+        answer.context(format!(
+            "Part {part} cannot be solved while reading the input"
+        ))?
+    });
+    let status = match result {
+        Ok(answer) => Status::Ok(answer),
+        Err(status) => status,
+    };
+
+    Outcome {
+        status,
+        parse: Duration::ZERO,
+        solve,
+    }
+}
+
+fn solve(solution: &dyn AnySolution, part: Part, input: &dyn Any) -> Result<Answer> {
+    match part {
+        Part::One => solution.compute_1(input),
+        Part::Two => solution.compute_2(input),
+    }
+}
+
+/// Times `f`, turning both errors and panics into a failed status.
+fn guarded<T>(f: impl FnOnce() -> Result<T>) -> (Result<T, Status>, Duration) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let elapsed = start.elapsed();
+
+    let result = match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(Status::Error(err)),
+        Err(payload) => Err(panic_status(payload.as_ref())),
+    };
+
+    (result, elapsed)
+}
+
+impl Status {
+    /// Copies a failed status, as `anyhow::Error` cannot be cloned.
+    fn duplicate(&self) -> Self {
+        match self {
+            Status::Ok(answer) => Status::Ok(answer.clone()),
+            Status::Error(err) => Status::Error(anyhow!("{err:#}")),
+            Status::Unimplemented => Status::Unimplemented,
+        }
+    }
+}
+
+fn panic_status(payload: &(dyn Any + Send)) -> Status {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+
+    if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+        Status::Unimplemented
+    } else {
+        Status::Error(anyhow!("panicked: {message}"))
+    }
+}
+
+#[derive(Debug)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        Self { samples }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+/// Times parsing the input over `runs` repetitions after `warmup` discarded
+/// runs.
+pub fn bench_parse(
+    solution: &dyn AnySolution,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Timings> {
+    bench(warmup, runs, || {
+        let (parsed, elapsed) = guarded(|| solution.parse(input));
+
+        check(parsed, "Parsing").map(|_| elapsed)
+    })
+}
+
+/// Times a part over `runs` repetitions after `warmup` discarded runs. The
+/// input is parsed once up front, so only solving is measured.
+pub fn bench_part(
+    solution: &dyn AnySolution,
+    part: Part,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Timings> {
+    let parsed = check(guarded(|| solution.parse(input)).0, "Parsing")?;
+
+    bench(warmup, runs, || {
+        let (answer, elapsed) = guarded(|| solve(solution, part, parsed.as_ref()));
+
+        check(answer, &format!("Part {part}")).map(|_| elapsed)
+    })
+}
+
+fn bench(
+    warmup: usize,
+    runs: usize,
+    mut sample: impl FnMut() -> Result<Duration>,
+) -> Result<Timings> {
+    if runs == 0 {
+        bail!("At least one run is needed to benchmark");
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+
+    for i in 0..warmup + runs {
+        let elapsed = sample()?;
+
+        if i >= warmup {
+            samples.push(elapsed);
+        }
+    }
+
+    Ok(Timings::new(samples))
+}
+
+fn check<T>(result: Result<T, Status>, step: &str) -> Result<T> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(Status::Error(err)) => Err(err.context(format!("{step} failed"))),
+        Err(_) => bail!("{step} is not implemented"),
+    }
+}
+
+/// Runs a part against an example, turning a wrong answer into an error.
+/// Returns `None` when the example has no answer for the part.
+pub fn run_example(solution: &dyn AnySolution, part: Part, example: &Example) -> Option<Outcome> {
+    let expected = match part {
+        Part::One => example.part_1?,
+        Part::Two => example.part_2?,
+    };
+    let outcome = run_part(solution, part, example.input);
+
+    Some(check_answer(outcome, expected))
+}
+
+/// Turns a wrong answer into an error.
+pub fn check_answer(mut outcome: Outcome, expected: &str) -> Outcome {
+    if let Status::Ok(answer) = &outcome.status {
+        if answer != expected {
+            outcome.status = Status::Error(anyhow!("Expected {expected} but got {answer}"));
+        }
+    }
+
+    outcome
+}
+
+/// A generated input on which a part disagrees with its reference
+/// implementation.
+#[derive(Debug)]
+pub struct Divergence {
+    pub seed: u64,
+    pub size: usize,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            seed,
+            size,
+            part,
+            input,
+            expected,
+            actual,
+        } = self;
+
+        writeln!(
+            f,
+            "Part {part} got {actual} where the reference got {expected} on seed {seed}, size {size}:"
+        )?;
+        write!(f, "{input}")
+    }
+}
+
+/// What comparing a day with its reference implementations found.
+#[derive(Debug)]
+pub enum Differential {
+    Agreed,
+    Diverged(Divergence),
+    /// The day has no input generator or no reference implementation, with
+    /// which one is missing.
+    NothingToCompare(&'static str),
+}
+
+/// Runs both parts and their reference implementations on `runs` generated
+/// inputs and returns the first one they disagree on. The inputs grow from
+/// size 1 up to `size`, so that the first divergence tends to be a small one,
+/// and are seeded with `seed`, `seed + 1` and so on.
+pub fn differential(
+    solution: &dyn AnySolution,
+    seed: u64,
+    runs: usize,
+    size: usize,
+) -> Result<Differential> {
+    let mut compared = 0;
+
+    for run in 0..runs {
+        let seed = seed.wrapping_add(run as u64);
+        let size = 1 + run * size.saturating_sub(1) / runs.saturating_sub(1).max(1);
+        let Some(input) = solution.generate(&mut Rng::new(seed), size) else {
+            return Ok(Differential::NothingToCompare(
+                "There is no input generator to test with",
+            ));
+        };
+        let parsed = check(guarded(|| solution.parse(&input)).0, "Parsing")
+            .context(format!("Generated input with seed {seed} is not valid"))?;
+
+        for part in Part::ALL {
+            let reference = match part {
+                Part::One => guarded(|| Ok(solution.reference_1(parsed.as_ref()))).0,
+                Part::Two => guarded(|| Ok(solution.reference_2(parsed.as_ref()))).0,
+            };
+            let expected = match reference {
+                Ok(None) => continue,
+                Ok(Some(result)) => describe(result.map_err(Status::Error)),
+                Err(status) => describe(Err(status)),
+            };
+            let actual = describe(guarded(|| solve(solution, part, parsed.as_ref())).0);
+
+            compared += 1;
+
+            if let (Some(expected), Some(actual)) = (expected, actual) {
+                if expected != actual {
+                    return Ok(Differential::Diverged(Divergence {
+                        seed,
+                        size,
+                        part,
+                        input,
+                        expected,
+                        actual,
+                    }));
+                }
+            }
+        }
+    }
+
+    if compared == 0 {
+        return Ok(Differential::NothingToCompare(
+            "There is no reference implementation to compare with",
+        ));
+    }
+
+    Ok(Differential::Agreed)
+}
+
+/// How a result reads in a divergence, or `None` when the part is not
+/// implemented and there is nothing to compare.
+fn describe(result: Result<Answer, Status>) -> Option<String> {
+    match result {
+        Ok(answer) => Some(answer.to_string()),
+        Err(Status::Error(err)) => Some(format!("an error ({err:#})")),
+        Err(_) => None,
+    }
+}
+
+/// Runs `job` for every item on `jobs` worker threads and hands the results to
+/// `emit` in the order of `items`, each one as soon as it and all earlier ones
+/// are done. Stops handing out work once `emit` fails.
+pub fn run_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    job: impl Fn(&T) -> R + Sync,
+    mut emit: impl FnMut(R) -> Result<()>,
+) -> Result<()>
+where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let (sender, next, job) = (sender.clone(), &next, &job);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                if sender.send((i, job(item))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut emitted = 0;
+
+        for (i, result) in receiver {
+            finished.insert(i, result);
+
+            while let Some(result) = finished.remove(&emitted) {
+                emit(result)?;
+                emitted += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
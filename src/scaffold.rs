@@ -0,0 +1,199 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::api::day_number;
+
+const SOLUTION: &str = "use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[];
+
+pub struct Day {}
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(ToString::to_string).collect())
+    }
+
+    fn compute_1(&self, _input: &Self::Input) -> Result<Answer> {
+        todo!()
+    }
+
+    fn compute_2(&self, _input: &Self::Input) -> Result<Answer> {
+        todo!()
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+";
+
+const YEAR_MODULE: &str = "use crate::registry;
+
+registry::days! {
+}
+";
+
+/// Adds a new day to the project rooted at `root`: an unsolved solution in
+/// `src/yYYYY/day_NN.rs` declared in its year's module and registered in its
+/// `days!` list, an empty `[YYYY.NN]` table in `answers.toml` and an empty
+/// `input/YYYY/NN`. A year that does not exist yet is created, declared and
+/// registered as well. Returns the files that were created or changed.
+pub fn new_day(root: &Path, year: u16, day: &str) -> Result<Vec<PathBuf>> {
+    let day = format!("{:02}", day_number(day)?);
+    let module = format!("day_{day}");
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let source = year_dir.join(format!("{module}.rs"));
+
+    if source.exists() {
+        bail!("{} already exists", source.display());
+    }
+
+    let mut touched = vec![];
+
+    let year_module = year_dir.join("mod.rs");
+
+    if !year_module.exists() {
+        let lib = root.join("src").join("lib.rs");
+
+        create(&year_module, YEAR_MODULE)?;
+        declare(&lib, "years!", &format!("y{year}"))?;
+        register(&lib, "years!", &format!("y{year}"))?;
+
+        touched.push(lib);
+    }
+
+    create(&source, SOLUTION)?;
+    declare(&year_module, "days!", &module)?;
+    register(&year_module, "days!", &module)?;
+
+    touched.push(source);
+    touched.push(year_module);
+
+    // The answers are filled in once they are known, which also enables the
+    // day's generated test
+    let answers = root.join("answers.toml");
+    let table = format!("[{year}.{day}]");
+    let contents = if answers.exists() {
+        read(&answers)?
+    } else {
+        String::new()
+    };
+
+    if !contents.lines().any(|line| line.trim() == table) {
+        write(&answers, &format!("{contents}\n{table}\n"))?;
+        touched.push(answers);
+    }
+
+    let input = root.join("input").join(year.to_string()).join(&day);
+
+    if !input.exists() {
+        create(&input, "")?;
+        touched.push(input);
+    }
+
+    Ok(touched)
+}
+
+/// Adds a `mod module;` item to `file` among the others, keeping them sorted,
+/// or above the `macro_name` invocation if it is the first.
+fn declare(file: &Path, macro_name: &str, module: &str) -> Result<()> {
+    let contents = read(file)?;
+    let item = format!("mod {module};");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let is_item = |line: &&str| line.starts_with("mod ") && line.ends_with(';');
+
+    if lines.contains(&item.as_str()) {
+        bail!("{module} is already declared in {}", file.display());
+    }
+
+    if let Some(last) = lines.iter().rposition(is_item) {
+        let first = lines.iter().position(is_item).unwrap_or(last);
+        let at = (first..=last)
+            .find(|&i| lines[i] > item.as_str())
+            .unwrap_or(last + 1);
+
+        lines.insert(at, &item);
+    } else {
+        let invocation = format!("registry::{macro_name}");
+        let at = lines
+            .iter()
+            .position(|line| line.starts_with(&invocation))
+            .context(format!(
+                "No {macro_name} invocation found in {}",
+                file.display()
+            ))?;
+
+        lines.splice(at..at, [item.as_str(), ""]);
+    }
+
+    write(file, &format!("{}\n", lines.join("\n")))
+}
+
+/// Adds `module` to the list passed to the `macro_name` invocation in `file`,
+/// keeping the list sorted.
+fn register(file: &Path, macro_name: &str, module: &str) -> Result<()> {
+    let contents = read(file)?;
+    let opening = format!("{macro_name} {{");
+    let list_start = contents
+        .find(&opening)
+        .context(format!("No {macro_name} list found in {}", file.display()))?
+        + opening.len();
+    let list_end = list_start
+        + contents[list_start..].find('}').context(format!(
+            "The {macro_name} list in {} is not closed",
+            file.display()
+        ))?;
+
+    let mut modules: Vec<&str> = contents[list_start..list_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect();
+
+    if modules.contains(&module) {
+        bail!("{module} is already registered in {}", file.display());
+    }
+
+    modules.push(module);
+    modules.sort_unstable();
+
+    let list: String = modules
+        .iter()
+        .map(|module| format!("\n    {module},"))
+        .collect();
+    let contents = format!(
+        "{}{list}\n{}",
+        &contents[..list_start],
+        &contents[list_end..]
+    );
+
+    write(file, &contents)
+}
+
+fn create(file: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = file.parent() {
+        fs::create_dir_all(dir).context(format!("Failed to create {}", dir.display()))?;
+    }
+
+    write(file, contents)
+}
+
+fn read(file: &Path) -> Result<String> {
+    fs::read_to_string(file).context(format!("Failed to read {}", file.display()))
+}
+
+fn write(file: &Path, contents: &str) -> Result<()> {
+    fs::write(file, contents).context(format!("Failed to write {}", file.display()))
+}
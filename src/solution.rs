@@ -1,4 +1,123 @@
-pub trait Solution {
-    fn compute_1(&self, input: &str) -> anyhow::Result<String>;
-    fn compute_2(&self, input: &str) -> anyhow::Result<String>;
+use std::{any::Any, io::BufRead};
+
+use anyhow::{Context, Result};
+
+use crate::{answer::Answer, generate::Rng};
+
+/// A day's solution. The input is parsed once and the result is shared by
+/// both parts. Solutions are stateless, so days can run on separate threads.
+pub trait Solution: Send + Sync {
+    type Input: 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn compute_1(&self, input: &Self::Input) -> Result<Answer>;
+    fn compute_2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// The worked examples from the puzzle text, used by `--example`.
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    /// A random input that is valid for the puzzle, for testing and stress
+    /// testing beyond the examples. `size` roughly scales the number of lines
+    /// or items.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// A deliberately naive solution of part 1 to check `compute_1` against
+    /// when its logic is optimized.
+    fn reference_1(&self, _input: &Self::Input) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// A deliberately naive solution of part 2 to check `compute_2` against
+    /// when its logic is optimized.
+    fn reference_2(&self, _input: &Self::Input) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// Solves part 1 while reading the input, in memory that does not grow
+    /// with it, for inputs too large to read at once. Only days whose input
+    /// can be handled a line at a time have one.
+    fn stream_1(&self, _input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// Solves part 2 while reading the input, like `stream_1`.
+    fn stream_2(&self, _input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        None
+    }
+}
+
+/// An example input together with the answers the puzzle text gives for it.
+/// Some examples are only given for one of the parts.
+pub struct Example {
+    pub input: &'static str,
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+/// A `Solution` with its input type erased, so that days with different input
+/// types can be registered and run side by side.
+pub trait AnySolution: Send + Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn compute_1(&self, input: &dyn Any) -> Result<Answer>;
+    fn compute_2(&self, input: &dyn Any) -> Result<Answer>;
+    fn examples(&self) -> &'static [Example];
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+    fn reference_1(&self, input: &dyn Any) -> Option<Result<Answer>>;
+    fn reference_2(&self, input: &dyn Any) -> Option<Result<Answer>>;
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>>;
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>>;
+}
+
+impl<S: Solution> AnySolution for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn compute_1(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::compute_1(self, downcast::<S>(input)?)
+    }
+
+    fn compute_2(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::compute_2(self, downcast::<S>(input)?)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Solution::examples(self)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn reference_1(&self, input: &dyn Any) -> Option<Result<Answer>> {
+        match downcast::<S>(input) {
+            Ok(input) => Solution::reference_1(self, input),
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn reference_2(&self, input: &dyn Any) -> Option<Result<Answer>> {
+        match downcast::<S>(input) {
+            Ok(input) => Solution::reference_2(self, input),
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Solution::stream_1(self, input)
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Solution::stream_2(self, input)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref()
+        .context("Input was not parsed by this solution")
 }
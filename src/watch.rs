@@ -0,0 +1,57 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Notices changes to a set of files by polling their modification times.
+/// Files that do not exist yet are watched too, and count as changed once
+/// they appear.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let path = path.as_ref().to_path_buf();
+                let modified = modified(&path);
+
+                (path, modified)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// Whether any file changed since the last call, or since the watcher was
+    /// created.
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+
+        for (path, last) in &mut self.files {
+            let modified = modified(path);
+
+            if modified != *last {
+                *last = modified;
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Blocks until a file changes, checking every `interval`.
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.changed() {
+            thread::sleep(interval);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
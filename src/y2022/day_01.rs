@@ -0,0 +1,126 @@
+use std::io::BufRead;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse,
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000",
+    part_1: Some("24000"),
+    part_2: Some("45000"),
+}];
+
+pub struct Day {}
+
+impl Solution for Day {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input
+            .split("\n\n")
+            .map(|chunk| {
+                chunk
+                    .lines()
+                    .map(|line| parse::number(input, line))
+                    .collect::<Result<Vec<usize>, _>>()
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    fn compute_1(&self, snacks_by_elf: &Self::Input) -> Result<Answer> {
+        let answer = snacks_by_elf
+            .iter()
+            .map(|snacks| snacks.iter().sum::<usize>())
+            .max()
+            .context("No fattest elf found")?;
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, snacks_by_elf: &Self::Input) -> Result<Answer> {
+        let mut calories_by_elf = snacks_by_elf
+            .iter()
+            .map(|snacks| snacks.iter().sum::<usize>())
+            .collect::<Vec<usize>>();
+
+        calories_by_elf.sort_unstable();
+        calories_by_elf.reverse();
+
+        let answer: usize = calories_by_elf.into_iter().take(3).sum();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let snacks: Vec<String> = (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=60000).to_string())
+                    .collect();
+
+                snacks.join("\n")
+            })
+            .collect();
+
+        Some(elves.join("\n\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(top_elves(input).map(|[fattest]| fattest.into()))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(top_elves::<3>(input).map(|top| top.iter().sum::<usize>().into()))
+    }
+}
+
+/// The calories carried by the `N` elves carrying the most, adding up one
+/// elf at a time as the input is read.
+fn top_elves<const N: usize>(input: &mut dyn BufRead) -> Result<[usize; N]> {
+    let mut top = [0; N];
+    let mut elf = 0;
+
+    let mut finish_elf = |elf: usize| {
+        if let Some(least) = top.iter_mut().min() {
+            *least = elf.max(*least);
+        }
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.context("Failed to read the input")?;
+
+        if line.is_empty() {
+            finish_elf(elf);
+            elf = 0;
+        } else {
+            elf += parse::number::<usize>(&line, &line).map_err(|err| err.on_line(i + 1))?;
+        }
+    }
+
+    finish_elf(elf);
+
+    Ok(top)
+}
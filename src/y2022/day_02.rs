@@ -0,0 +1,198 @@
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::Result;
+use Hand::{Paper, Rock, Scissor};
+use Outcome::{Draw, Lose, Win};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "A" | "X" => Ok(Rock),
+            "B" | "Y" => Ok(Paper),
+            "C" | "Z" => Ok(Scissor),
+            _ => Err(ParseError::at(s, s, format!("{s:?} is not a hand"))),
+        }
+    }
+}
+
+impl Hand {
+    fn value(self) -> u32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissor => 3,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "X" => Ok(Lose),
+            "Y" => Ok(Draw),
+            "Z" => Ok(Win),
+            _ => Err(ParseError::at(s, s, format!("{s:?} is not an outcome"))),
+        }
+    }
+}
+
+impl Outcome {
+    fn value(self) -> u32 {
+        match self {
+            Lose => 0,
+            Draw => 3,
+            Win => 6,
+        }
+    }
+}
+
+fn calculate_fight(fight: (Hand, Hand)) -> u32 {
+    match fight {
+        (Rock, Scissor) | (Paper, Rock) | (Scissor, Paper) => 6,
+        (you, other) if you == other => 3,
+        _ => 0,
+    }
+}
+
+fn find_hand(outcome: Outcome, hand: Hand) -> Hand {
+    match (outcome, hand) {
+        (Draw, _) => hand,
+        (Win, Rock) | (Lose, Scissor) => Paper,
+        (Win, Paper) | (Lose, Rock) => Scissor,
+        (Win, Scissor) | (Lose, Paper) => Rock,
+    }
+}
+
+/// A round of the strategy guide, with the second column read both as a hand
+/// for part 1 and as the desired outcome for part 2.
+pub struct Round {
+    other: Hand,
+    you: Hand,
+    outcome: Outcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (other, column) = parse::split_once(s, " ")?;
+
+        Ok(Self {
+            other: parse::within(s, other)?,
+            you: parse::within(s, column)?,
+            outcome: parse::within(s, column)?,
+        })
+    }
+}
+
+/// The score of a round when the second column is the hand to play.
+fn score_1(round: &Round) -> u32 {
+    let you = round.you;
+
+    let fight = (you, round.other);
+    let fight_value = calculate_fight(fight);
+    let hand_value = you.value();
+
+    fight_value + hand_value
+}
+
+/// The score of a round when the second column is how it has to end.
+fn score_2(round: &Round) -> u32 {
+    let outcome = round.outcome;
+    let you = find_hand(outcome, round.other);
+
+    let fight_value = outcome.value();
+    let hand_value = you.value();
+
+    fight_value + hand_value
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "A Y
+B X
+C Z",
+    part_1: Some("15"),
+    part_2: Some("12"),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn compute_1(&self, strategy: &Self::Input) -> Result<Answer> {
+        let answer: u32 = strategy.iter().map(score_1).sum();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, strategy: &Self::Input) -> Result<Answer> {
+        let answer: u32 = strategy.iter().map(score_2).sum();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let rounds: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let other = rng.pick(&["A", "B", "C"]);
+                let column = rng.pick(&["X", "Y", "Z"]);
+
+                format!("{other} {column}")
+            })
+            .collect();
+
+        Some(rounds.join("\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(total_score(input, score_1))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(total_score(input, score_2))
+    }
+}
+
+/// Adds up the score of every round as the input is read.
+fn total_score(input: &mut dyn BufRead, score: fn(&Round) -> u32) -> Result<Answer> {
+    let mut total = 0;
+
+    for round in parse::read_lines(input) {
+        total += score(&round?);
+    }
+
+    Ok(total.into())
+}
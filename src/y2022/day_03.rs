@@ -0,0 +1,175 @@
+use std::{collections::HashSet, io::BufRead};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    solution::{Example, Solution},
+};
+
+fn item_value(item: char) -> u32 {
+    let offset = if item.is_lowercase() { 96 } else { 38 };
+    let value = item as u32;
+
+    value - offset
+}
+
+fn find_shared_item_simple(a: &str, b: &str) -> Option<char> {
+    let set_a: HashSet<char> = a.chars().collect();
+    let set_b: HashSet<char> = b.chars().collect();
+
+    let shared_item = set_a.intersection(&set_b).next().copied();
+
+    shared_item
+}
+
+fn find_shared_item_advanced(lines: &[String]) -> Option<char> {
+    let shared_item = lines
+        .iter()
+        .map(|line| line.chars().collect::<HashSet<char>>())
+        .reduce(|acc, next| acc.intersection(&next).copied().collect::<HashSet<char>>())
+        .and_then(|set| set.iter().next().copied());
+
+    shared_item
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+    part_1: Some("157"),
+    part_2: Some("70"),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(ToString::to_string).collect())
+    }
+
+    fn compute_1(&self, rucksacks: &Self::Input) -> Result<Answer> {
+        let answer: u32 = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let mid = rucksack.len() / 2;
+                let (compartment_1, compartment_2) = rucksack.split_at(mid);
+                let shared_item = find_shared_item_simple(compartment_1, compartment_2)
+                    .expect("found no shared item");
+
+                item_value(shared_item)
+            })
+            .sum();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, rucksacks: &Self::Input) -> Result<Answer> {
+        let answer: u32 = rucksacks
+            .chunks_exact(3)
+            .map(|rucksacks| {
+                let shared_item =
+                    find_shared_item_advanced(rucksacks).expect("found no shared item");
+
+                item_value(shared_item)
+            })
+            .sum();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let rucksacks: Vec<String> = (0..size.div_ceil(3).max(1))
+            .flat_map(|_| generate_group(rng))
+            .collect();
+
+        Some(rucksacks.join("\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        let answer = input.lines().try_fold(0, |total, rucksack| {
+            let rucksack = rucksack.context("Failed to read the input")?;
+            let (compartment_1, compartment_2) = rucksack.split_at(rucksack.len() / 2);
+            let shared_item = find_shared_item_simple(compartment_1, compartment_2)
+                .context(format!("Found no shared item in {rucksack}"))?;
+
+            Ok(total + item_value(shared_item))
+        });
+
+        Some(answer.map(|total: u32| total.into()))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(sum_badges(input))
+    }
+}
+
+/// Three rucksacks that only have their badge in common, each with a single
+/// item in both compartments.
+fn generate_group(rng: &mut Rng) -> Vec<String> {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    rng.shuffle(&mut items);
+
+    let (badge, items) = (items[0], &items[1..]);
+
+    // Every elf draws from items of its own so that nothing but the badge is
+    // shared within the group
+    items
+        .chunks(17)
+        .map(|items| {
+            let (first_items, second_items) = items.split_at(8);
+            let shared = *rng.pick(&[second_items[8], badge]);
+            let half = rng.range(2..=12);
+
+            let mut first = vec![shared];
+            let mut second = vec![shared];
+
+            if shared != badge {
+                first.push(badge);
+            }
+
+            while first.len() < half {
+                first.push(*rng.pick(first_items));
+            }
+
+            while second.len() < half {
+                second.push(*rng.pick(&second_items[..8]));
+            }
+
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+
+            first.into_iter().chain(second).collect()
+        })
+        .collect()
+}
+
+/// Adds up the badge of every group as the input is read, holding one group
+/// at a time.
+fn sum_badges(input: &mut dyn BufRead) -> Result<Answer> {
+    let mut total = 0;
+    let mut group = Vec::with_capacity(3);
+
+    for rucksack in input.lines() {
+        group.push(rucksack.context("Failed to read the input")?);
+
+        if group.len() == 3 {
+            let badge = find_shared_item_advanced(&group)
+                .context(format!("Found no badge in {group:?}"))?;
+
+            total += item_value(badge);
+            group.clear();
+        }
+    }
+
+    Ok(total.into())
+}
@@ -0,0 +1,183 @@
+use std::{collections::HashSet, io::BufRead, ops::RangeInclusive, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+pub struct Interval {
+    start: usize,
+    end: usize,
+}
+
+impl Interval {
+    fn contains_point(&self, point: usize) -> bool {
+        self.start <= point && self.end >= point
+    }
+
+    fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Interval) -> bool {
+        self.contains_point(other.start) || self.contains_point(other.end)
+    }
+
+    fn sections(&self) -> RangeInclusive<usize> {
+        self.start..=self.end
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (start, end) = parse::split_once(s, "-")?;
+        let start: usize = parse::number(s, start)?;
+        let end: usize = parse::number(s, end)?;
+
+        Ok(Self { start, end })
+    }
+}
+
+pub struct Pair {
+    first: Interval,
+    second: Interval,
+}
+
+impl Pair {
+    fn fully_contained(&self) -> bool {
+        self.first.contains(&self.second) || self.second.contains(&self.first)
+    }
+
+    fn overlapping(&self) -> bool {
+        self.first.overlaps(&self.second) || self.second.overlaps(&self.first)
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (first, second) = parse::split_once(s, ",")?;
+        let first: Interval = parse::within(s, first)?;
+        let second: Interval = parse::within(s, second)?;
+
+        Ok(Pair { first, second })
+    }
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8",
+    part_1: Some("2"),
+    part_2: Some("4"),
+}];
+
+pub struct Day {}
+
+impl Solution for Day {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn compute_1(&self, interval_pairs: &Self::Input) -> Result<Answer> {
+        let answer = interval_pairs
+            .iter()
+            .filter(|pair| pair.fully_contained())
+            .count();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, interval_pairs: &Self::Input) -> Result<Answer> {
+        let answer = interval_pairs
+            .iter()
+            .filter(|pair| pair.overlapping())
+            .count();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_1(&self, interval_pairs: &Self::Input) -> Option<Result<Answer>> {
+        let answer = interval_pairs
+            .iter()
+            .filter(|Pair { first, second }| {
+                let first: HashSet<usize> = first.sections().collect();
+                let second: HashSet<usize> = second.sections().collect();
+
+                first.is_subset(&second) || second.is_subset(&first)
+            })
+            .count();
+
+        Some(Ok(answer.into()))
+    }
+
+    fn reference_2(&self, interval_pairs: &Self::Input) -> Option<Result<Answer>> {
+        let answer = interval_pairs
+            .iter()
+            .filter(|Pair { first, second }| {
+                let first: HashSet<usize> = first.sections().collect();
+
+                second.sections().any(|section| first.contains(&section))
+            })
+            .count();
+
+        Some(Ok(answer.into()))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let first = generate_interval(rng);
+                let second = generate_interval(rng);
+
+                format!("{first},{second}")
+            })
+            .collect();
+
+        Some(pairs.join("\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(count_pairs(input, Pair::fully_contained))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(count_pairs(input, Pair::overlapping))
+    }
+}
+
+fn generate_interval(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    let end = rng.range(start..=99);
+
+    format!("{start}-{end}")
+}
+
+/// Counts the pairs that match `filter` as the input is read.
+fn count_pairs(input: &mut dyn BufRead, filter: fn(&Pair) -> bool) -> Result<Answer> {
+    let mut count = 0_usize;
+
+    for pair in parse::read_lines(input) {
+        if filter(&pair?) {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use nom::{
+    bytes::complete::tag,
+    combinator::{map, verify},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2",
+    ),
+    part_1: Some("CMZ"),
+    part_2: Some("MCD"),
+}];
+
+pub struct Day {}
+
+pub struct Instruction {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks(Vec<Vec<char>>);
+
+impl Stacks {
+    fn apply_9000(&mut self, instruction: &Instruction) -> Result<()> {
+        let Instruction { from, to, quantity } = *instruction;
+
+        for _ in 1..=quantity {
+            let krate = self
+                .stack(from)
+                .pop()
+                .context(format!("No crate to move at stack {from}"))?;
+
+            self.stack(to).push(krate);
+        }
+
+        Ok(())
+    }
+
+    fn apply_9001(&mut self, instruction: &Instruction) {
+        let Instruction { from, to, quantity } = *instruction;
+        let from_stack = self.stack(from);
+        let split = from_stack.len() - quantity;
+        let mut krates = from_stack.split_off(split);
+
+        self.stack(to).append(&mut krates);
+    }
+
+    fn top_crates(&self) -> Vec<char> {
+        self.0
+            .iter()
+            .filter_map(|stack| stack.last())
+            .copied()
+            .collect()
+    }
+
+    fn stack(&mut self, stack: usize) -> &mut Vec<char> {
+        &mut self.0[stack]
+    }
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let drawing = parse::all(
+            s,
+            parse::char_grid(' ', |c| {
+                (matches!(c, ' ' | '[' | ']') || c.is_ascii_alphanumeric()).then_some(c)
+            }),
+        )?;
+
+        // The crates of a stack are in the column of its number, on the last
+        // line of the drawing
+        let numbers = drawing.height() - 1;
+        let stacks = (0..drawing.width())
+            .filter(|&column| drawing[(numbers, column)].is_ascii_digit())
+            .map(|column| {
+                drawing
+                    .column(column)
+                    .rev()
+                    .skip(1)
+                    .copied()
+                    .filter(char::is_ascii_alphabetic)
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self(stacks))
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::all(s, parse_instruction)
+    }
+}
+
+fn parse_instruction(s: &str) -> IResult<&str, Instruction> {
+    // Stacks are numbered from 1
+    let stack = || map(verify(parse::unsigned::<usize>, |n| *n >= 1), |n| n - 1);
+
+    map(
+        tuple((
+            preceded(tag("move "), parse::unsigned),
+            preceded(tag(" from "), stack()),
+            preceded(tag(" to "), stack()),
+        )),
+        |(quantity, from, to)| Instruction { quantity, from, to },
+    )(s)
+}
+
+impl Solution for Day {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let (stacks, instructions) = parse::split_once(input, "\n\n")?;
+
+        let stacks: Stacks = parse::within(input, stacks)?;
+
+        let instructions: Vec<Instruction> =
+            parse::lines(instructions).map_err(|err| err.within(input, instructions))?;
+
+        Ok((stacks, instructions))
+    }
+
+    fn compute_1(&self, (stacks, instructions): &Self::Input) -> Result<Answer> {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            stacks.apply_9000(instruction)?;
+        }
+
+        let answer: String = stacks.top_crates().iter().collect();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, (stacks, instructions): &Self::Input) -> Result<Answer> {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            stacks.apply_9001(instruction);
+        }
+
+        let answer: String = stacks.top_crates().iter().collect();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let stacks: Vec<Vec<char>> = (0..rng.range(3..=9))
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| char::from(b'A' + rng.range(0..=25) as u8))
+                    .collect()
+            })
+            .collect();
+        let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let crates: Vec<String> = stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(krate) => format!("[{krate}]"),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+
+                crates.join(" ")
+            })
+            .collect();
+        let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+
+        lines.push(numbers.join(" "));
+        lines.push(String::new());
+
+        // Only the heights matter for a move to be valid, and they change the
+        // same way for both cranes
+        let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.range(0..=heights.len() - 1);
+
+                if heights[from] > 0 {
+                    break from;
+                }
+            };
+            let to = (from + rng.range(1..=heights.len() - 1)) % heights.len();
+            let quantity = rng.range(1..=heights[from]);
+
+            heights[from] -= quantity;
+            heights[to] += quantity;
+
+            lines.push(format!("move {quantity} from {} to {}", from + 1, to + 1));
+        }
+
+        Some(lines.join("\n"))
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Read},
+};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+        part_1: Some("7"),
+        part_2: Some("19"),
+    },
+    Example {
+        input: "bvwbjplbgvbhsrlpgdmjqwftvncz",
+        part_1: Some("5"),
+        part_2: Some("23"),
+    },
+    Example {
+        input: "nppdvjthqldpwncqszvftbrmjlhg",
+        part_1: Some("6"),
+        part_2: Some("23"),
+    },
+    Example {
+        input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+        part_1: Some("10"),
+        part_2: Some("29"),
+    },
+    Example {
+        input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+        part_1: Some("11"),
+        part_2: Some("26"),
+    },
+];
+
+pub struct Day {}
+
+fn find_marker(chars: &[char], length: usize) -> usize {
+    let mut answer = length;
+
+    for window in chars.windows(length) {
+        if window.iter().all_unique() {
+            break;
+        }
+        answer += 1;
+    }
+
+    answer
+}
+
+impl Solution for Day {
+    type Input = Vec<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.chars().collect())
+    }
+
+    fn compute_1(&self, chars: &Self::Input) -> Result<Answer> {
+        let answer = find_marker(chars, 4);
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, chars: &Self::Input) -> Result<Answer> {
+        let answer = find_marker(chars, 14);
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_1(&self, chars: &Self::Input) -> Option<Result<Answer>> {
+        Some(find_marker_naive(chars, 4).map(Answer::from))
+    }
+
+    fn reference_2(&self, chars: &Self::Input) -> Option<Result<Answer>> {
+        Some(find_marker_naive(chars, 14).map(Answer::from))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        // Repeating a few letters keeps the markers from showing up right away
+        let mut chars: Vec<char> = (0..size).map(|_| *rng.pick(&['a', 'b', 'c'])).collect();
+        let mut marker: Vec<char> = ('a'..='z').collect();
+
+        rng.shuffle(&mut marker);
+        chars.extend(&marker[..14]);
+        chars.extend((0..size).map(|_| char::from(b'a' + rng.range(0..=25) as u8)));
+
+        Some(chars.into_iter().collect())
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(find_marker_streamed(input, 4).map(Answer::from))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(find_marker_streamed(input, 14).map(Answer::from))
+    }
+}
+
+/// Compares every pair of characters in every window.
+fn find_marker_naive(chars: &[char], length: usize) -> Result<usize> {
+    (length..=chars.len())
+        .find(|end| {
+            let window = &chars[end - length..*end];
+
+            (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]))
+        })
+        .context("No marker found")
+}
+
+/// Like `find_marker`, but reading one character at a time and only keeping
+/// the last `length` of them.
+fn find_marker_streamed(input: &mut dyn BufRead, length: usize) -> Result<usize> {
+    let mut window = VecDeque::with_capacity(length);
+
+    for (i, byte) in Read::bytes(input).enumerate() {
+        let byte = byte.context("Failed to read the input")?;
+
+        if window.len() == length {
+            window.pop_front();
+        }
+
+        window.push_back(byte);
+
+        if window.len() == length && window.iter().all_unique() {
+            return Ok(i + 1);
+        }
+    }
+
+    bail!("No marker found")
+}
@@ -1,16 +1,49 @@
 use std::str::FromStr;
 
-use anyhow::{bail, Context, Result};
+use anyhow::{Context, Result};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::u32,
     combinator::{all_consuming, map, rest},
     sequence::{preceded, tuple},
-    Finish, IResult,
+    IResult,
 };
 
-use crate::solution::Solution;
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+    part_1: Some("95437"),
+    part_2: Some("24933642"),
+}];
 
 pub struct Day {}
 
@@ -121,15 +154,10 @@ fn parse_line(i: &str) -> IResult<&str, ConsoleLine> {
 }
 
 impl FromStr for ConsoleLine {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let parsed_line = all_consuming(parse_line)(s).finish();
+    type Err = ParseError;
 
-        match parsed_line {
-            Ok((_, console_line)) => Ok(console_line),
-            Err(err) => bail!("Failed to parse line {s} with error: {err}"),
-        }
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::finish(s, all_consuming(parse_line)(s))
     }
 }
 
@@ -207,37 +235,31 @@ impl State {
 }
 
 impl Solution for Day {
-    fn compute_1(&self, input: &str) -> Result<String> {
-        let lines: Result<Vec<ConsoleLine>> = input.lines().map(str::parse).collect();
+    /// The size of every directory, with the root last.
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let lines: Vec<ConsoleLine> = parse::lines(input)?;
 
         let mut state = State::new();
 
-        for line in lines? {
+        for line in lines {
             state.apply(line)?;
         }
 
-        let answer: usize = state
-            .tree
-            .directory_sizes()
-            .into_iter()
-            .filter(|size| *size <= MAX_SIZE)
-            .sum();
-
-        Ok(answer.to_string())
+        Ok(state.tree.directory_sizes())
     }
 
-    fn compute_2(&self, input: &str) -> Result<String> {
-        let lines: Result<Vec<ConsoleLine>> = input.lines().map(str::parse).collect();
-
-        let mut state = State::new();
+    fn compute_1(&self, sizes: &Self::Input) -> Result<Answer> {
+        let answer: usize = sizes.iter().filter(|size| **size <= MAX_SIZE).sum();
 
-        for line in lines? {
-            state.apply(line)?;
-        }
+        Ok(answer.into())
+    }
 
-        let root_size = state.tree.size();
+    fn compute_2(&self, sizes: &Self::Input) -> Result<Answer> {
+        let root_size = *sizes.last().context("No root directory found")?;
         let space_to_free = REQUIRED_SIZE - (AVAILABLE_SPACE - root_size);
-        let mut sizes = state.tree.directory_sizes();
+        let mut sizes = sizes.clone();
 
         sizes.sort_unstable();
 
@@ -246,6 +268,99 @@ impl Solution for Day {
             .find(|size| *size >= space_to_free)
             .context("No directory found")?;
 
-        Ok(answer.to_string())
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let directories = size.max(1);
+        let mut children: Vec<Vec<usize>> = vec![vec![]; directories];
+        let mut names = vec!["/".to_string()];
+
+        for directory in 1..directories {
+            let parent = rng.range(0..=directory - 1);
+            let name = loop {
+                let name = generate_name(rng);
+
+                if children[parent].iter().all(|&child| names[child] != name) {
+                    break name;
+                }
+            };
+
+            children[parent].push(directory);
+            names.push(name);
+        }
+
+        // The disk has to be full enough for part 2 to have to free space, so
+        // the file sizes are scaled to add up to between 40M and 70M
+        let files: Vec<Vec<(String, usize)>> = (0..directories)
+            .map(|directory| {
+                let min = usize::from(directory == 0);
+
+                (0..rng.range(min..=4))
+                    .map(|_| {
+                        let name = generate_name(rng);
+                        let name = if rng.chance(30) {
+                            format!("{name}.{}", rng.pick(&["txt", "dat", "log"]))
+                        } else {
+                            name
+                        };
+
+                        (name, rng.range(1..=1000))
+                    })
+                    .collect()
+            })
+            .collect();
+        let weights: usize = files.iter().flatten().map(|(_, weight)| weight).sum();
+        let total = rng.range(41_000_000..=69_000_000);
+        let files: Vec<Vec<(String, usize)>> = files
+            .into_iter()
+            .map(|files| {
+                files
+                    .into_iter()
+                    .map(|(name, weight)| (name, (total * weight / weights).max(1)))
+                    .collect()
+            })
+            .collect();
+
+        let mut lines = vec!["$ cd /".to_string()];
+
+        generate_transcript(0, &children, &names, &files, &mut lines);
+
+        Some(lines.join("\n"))
+    }
+}
+
+fn generate_name(rng: &mut Rng) -> String {
+    (0..rng.range(1..=8))
+        .map(|_| char::from(b'a' + rng.range(0..=25) as u8))
+        .collect()
+}
+
+/// Lists `directory` and then walks into each of its subdirectories.
+fn generate_transcript(
+    directory: usize,
+    children: &[Vec<usize>],
+    names: &[String],
+    files: &[Vec<(String, usize)>],
+    lines: &mut Vec<String>,
+) {
+    lines.push("$ ls".to_string());
+
+    for &child in &children[directory] {
+        lines.push(format!("dir {}", names[child]));
+    }
+
+    for (name, size) in &files[directory] {
+        lines.push(format!("{size} {name}"));
+    }
+
+    for &child in &children[directory] {
+        lines.push(format!("$ cd {}", names[child]));
+        generate_transcript(child, children, names, files, lines);
+        lines.push("$ cd ..".to_string());
     }
 }
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    geometry::Direction,
+    grid::{Grid, Position},
+    parse::ParseError,
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "30373
+25512
+65332
+33549
+35390",
+    part_1: Some("21"),
+    part_2: Some("8"),
+}];
+
+pub struct Day {}
+
+/// The heights of the trees.
+pub struct Forest {
+    trees: Grid<usize>,
+}
+
+impl Forest {
+    fn new(trees: Grid<usize>) -> Self {
+        Self { trees }
+    }
+
+    /// The heights of the trees seen from `(i, j)` towards the top, bottom,
+    /// left and right, nearest first.
+    fn lines_of_sight(&self, (i, j): Position) -> [Vec<usize>; 4] {
+        let column: Vec<usize> = self.trees.column(j).copied().collect();
+        let row = self.trees.row(i);
+
+        [
+            column[..i].iter().rev().copied().collect(),
+            column[i + 1..].to_vec(),
+            row[..j].iter().rev().copied().collect(),
+            row[j + 1..].to_vec(),
+        ]
+    }
+}
+
+impl FromStr for Forest {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(s, "a tree height", |c| {
+            c.to_digit(10).map(|height| height as usize)
+        })?;
+
+        Ok(Self::new(trees))
+    }
+}
+
+impl Forest {
+    fn is_tree_visible(&self, position: Position) -> bool {
+        let current_height = self.trees[position];
+
+        Direction::ALL.iter().any(|direction| {
+            self.trees
+                .ray(position, direction.delta())
+                .all(|other| self.trees[other] < current_height)
+        })
+    }
+
+    fn scenic_value(&self, position: Position) -> usize {
+        let current_height = self.trees[position];
+
+        Direction::ALL
+            .iter()
+            .map(|direction| {
+                let mut viewing_distance = 0_usize;
+
+                for other in self.trees.ray(position, direction.delta()) {
+                    viewing_distance += 1;
+
+                    if self.trees[other] >= current_height {
+                        break;
+                    }
+                }
+
+                viewing_distance
+            })
+            .product()
+    }
+}
+
+impl Solution for Day {
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn compute_1(&self, forest: &Self::Input) -> Result<Answer> {
+        let answer = forest
+            .trees
+            .positions()
+            .filter(|&position| forest.is_tree_visible(position))
+            .count();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, forest: &Self::Input) -> Result<Answer> {
+        let answer = forest
+            .trees
+            .positions()
+            .map(|position| forest.scenic_value(position))
+            .max()
+            .unwrap_or_default();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_1(&self, forest: &Self::Input) -> Option<Result<Answer>> {
+        let answer = forest
+            .trees
+            .positions()
+            .filter(|&(i, j)| {
+                let height = forest.trees[(i, j)];
+
+                forest
+                    .lines_of_sight((i, j))
+                    .iter()
+                    .any(|line| line.iter().all(|other| *other < height))
+            })
+            .count();
+
+        Some(Ok(answer.into()))
+    }
+
+    fn reference_2(&self, forest: &Self::Input) -> Option<Result<Answer>> {
+        let answer = forest
+            .trees
+            .positions()
+            .map(|(i, j)| {
+                let height = forest.trees[(i, j)];
+
+                forest
+                    .lines_of_sight((i, j))
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .position(|other| *other >= height)
+                            .map_or(line.len(), |blocked| blocked + 1)
+                    })
+                    .product::<usize>()
+            })
+            .max()
+            .unwrap_or_default();
+
+        Some(Ok(answer.into()))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(1);
+        let rows: Vec<String> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| char::from(b'0' + rng.range(0..=9) as u8))
+                    .collect()
+            })
+            .collect();
+
+        Some(rows.join("\n"))
+    }
+}
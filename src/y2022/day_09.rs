@@ -0,0 +1,212 @@
+use std::{collections::HashSet, fmt::Debug, io::BufRead, str::FromStr};
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    geometry::{Direction, Point},
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Debug for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min_x, max_x) = self
+            .knots
+            .iter()
+            .map(|knot| knot.x())
+            .minmax()
+            .into_option()
+            .unwrap();
+        let (min_y, max_y) = self
+            .knots
+            .iter()
+            .map(|knot| knot.y())
+            .minmax()
+            .into_option()
+            .unwrap();
+
+        let mut lines: Vec<String> = vec![];
+        for y in min_y..=max_y {
+            let mut line: String = String::new();
+            for x in min_x..=max_x {
+                let point = Point::new(x, y);
+
+                match self.knots.iter().position(|knot| *knot == point) {
+                    Some(0) => line.push('H'),
+                    Some(index) => line.push_str(&index.to_string()),
+                    None if point == Point::ORIGIN => line.push('s'),
+                    None => line.push('.'),
+                }
+            }
+            lines.push(line);
+        }
+
+        write!(f, "\n{}", lines.join("\n"))
+    }
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        Self {
+            knots: vec![Point::ORIGIN; length],
+        }
+    }
+
+    fn apply_instruction(&mut self, instruction: &Instruction) -> Vec<Point> {
+        let mut visited_points = vec![];
+        for _ in 0..instruction.1 {
+            let visited_point = self.apply_direction(instruction.0);
+            visited_points.push(visited_point);
+        }
+
+        visited_points
+    }
+
+    fn apply_direction(&mut self, direction: Direction) -> Point {
+        self.knots[0] += direction.delta();
+
+        for i in 1..self.knots.len() {
+            let gap = self.knots[i - 1] - self.knots[i];
+
+            // A knot only moves once it no longer touches the one before it,
+            // and then takes a single step, diagonal if need be, towards it
+            if gap.chebyshev() > 1 {
+                self.knots[i] += gap.signum();
+            }
+        }
+
+        self.knots[self.knots.len() - 1]
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction(Direction, usize);
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Direction::{Down, Left, Right, Up};
+
+        let (d, n) = parse::split_once(s, " ")?;
+        let direction = match d {
+            "U" => Up,
+            "D" => Down,
+            "L" => Left,
+            "R" => Right,
+            _ => return Err(ParseError::at(s, d, format!("'{d}' is not one of U/D/L/R"))),
+        };
+        let steps: usize = parse::number(s, n)?;
+
+        Ok(Instruction(direction, steps))
+    }
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2",
+        part_1: Some("13"),
+        part_2: Some("1"),
+    },
+    Example {
+        input: "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20",
+        part_1: None,
+        part_2: Some("36"),
+    },
+];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn compute_1(&self, instructions: &Self::Input) -> Result<Answer> {
+        let mut rope = Rope::new(2);
+        let mut all_points = vec![];
+
+        for instruction in instructions {
+            let mut points = rope.apply_instruction(instruction);
+            all_points.append(&mut points);
+        }
+
+        let answer = all_points.into_iter().unique().count();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, instructions: &Self::Input) -> Result<Answer> {
+        let mut rope = Rope::new(10);
+        let mut all_points = vec![];
+
+        for instruction in instructions {
+            let mut points = rope.apply_instruction(instruction);
+            all_points.append(&mut points);
+        }
+
+        let answer = all_points.into_iter().unique().count();
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let moves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let direction = rng.pick(&["U", "D", "L", "R"]);
+
+                format!("{direction} {}", rng.range(1..=20))
+            })
+            .collect();
+
+        Some(moves.join("\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(count_tail_positions(input, 2))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        Some(count_tail_positions(input, 10))
+    }
+}
+
+/// Moves a rope of `knots` knots as the input is read. Only the positions the
+/// tail has been in are kept, so memory grows with the number of distinct
+/// tail positions rather than with the size of the input.
+fn count_tail_positions(input: &mut dyn BufRead, knots: usize) -> Result<Answer> {
+    let mut rope = Rope::new(knots);
+    let mut visited = HashSet::new();
+
+    for instruction in parse::read_lines(input) {
+        visited.extend(rope.apply_instruction(&instruction?));
+    }
+
+    Ok(visited.len().into())
+}
@@ -0,0 +1,372 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{Context, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{all_consuming, map},
+    sequence::preceded,
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Noop,
+    Addx(usize, i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::finish(s, all_consuming(parse_instruction)(s))
+    }
+}
+
+/// The cycles whose signal strength counts in part 1.
+const SIGNAL_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+#[derive(Debug)]
+struct State {
+    x: i32,
+    instructions: Vec<Instruction>,
+}
+
+impl State {
+    fn new(instructions: Vec<Instruction>) -> Self {
+        Self { x: 1, instructions }
+    }
+
+    fn tick(&mut self) -> Result<()> {
+        let instruction = self
+            .instructions
+            .last_mut()
+            .context("No more instructions left")?;
+
+        match instruction {
+            Instruction::Noop => {
+                self.instructions.pop();
+            }
+            Instruction::Addx(cycles, n) => {
+                if *cycles == 1 {
+                    self.x += *n;
+                    self.instructions.pop();
+                } else {
+                    *instruction = Instruction::Addx(*cycles - 1, *n);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_instruction(s: &str) -> IResult<&str, Instruction> {
+    let parse_noop = tag("noop");
+    let parse_addx = preceded(tag("addx "), parse::signed);
+
+    alt((
+        map(parse_noop, |_| Instruction::Noop),
+        map(parse_addx, |n| Instruction::Addx(2, n)),
+    ))(s)
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+    part_1: Some("13140"),
+    part_2: Some(concat!(
+        "\n■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ",
+        "\n■■■   ■■■   ■■■   ■■■   ■■■   ■■■   ■■■ ",
+        "\n■■■■    ■■■■    ■■■■    ■■■■    ■■■■    ",
+        "\n■■■■■     ■■■■■     ■■■■■     ■■■■■     ",
+        "\n■■■■■■      ■■■■■■      ■■■■■■      ■■■■",
+        "\n■■■■■■■       ■■■■■■■       ■■■■■■■     ",
+    )),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    /// The program in reverse, so the next instruction can be popped off the end.
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mut instructions: Vec<Instruction> = parse::lines(input)?;
+
+        instructions.reverse();
+
+        Ok(instructions)
+    }
+
+    fn compute_1(&self, instructions: &Self::Input) -> Result<Answer> {
+        let mut state = State::new(instructions.clone());
+
+        let mut answer = vec![];
+
+        for i in 1..=220 {
+            if SIGNAL_CYCLES.contains(&i) {
+                answer.push(state.x * i);
+            }
+
+            state.tick()?;
+        }
+
+        let answer: i32 = answer.into_iter().sum();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, instructions: &Self::Input) -> Result<Answer> {
+        let mut state = State::new(instructions.clone());
+
+        let rows: Vec<String> = (1..=6)
+            .map(|_| {
+                (0..=39)
+                    .map(|i| {
+                        let pixel = if state.x - 1 <= i && i <= state.x + 1 {
+                            '■'
+                        } else {
+                            ' '
+                        };
+
+                        state.tick()?;
+
+                        Ok(pixel)
+                    })
+                    .collect::<Result<String>>()
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Answer::Grid(rows))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let mut lines = vec![];
+        let mut cycles = 0;
+        let mut x = 1;
+
+        // Part 2 draws 240 pixels, so there have to be at least that many
+        // cycles
+        while cycles < size.max(240) {
+            if rng.chance(30) {
+                lines.push("noop".to_string());
+                cycles += 1;
+            } else {
+                let n = (rng.range(0..=39) as i32 - x).clamp(-15, 15);
+
+                lines.push(format!("addx {n}"));
+                x += n;
+                cycles += 2;
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    fn stream_1(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        let mut answer = 0;
+        let result = run_streamed(input, 220, |cycle, x| {
+            if SIGNAL_CYCLES.contains(&cycle) {
+                answer += x * cycle;
+            }
+        });
+
+        Some(result.map(|_| answer.into()))
+    }
+
+    fn stream_2(&self, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        let mut rows = vec![String::new(); 6];
+        let result = run_streamed(input, 240, |cycle, x| {
+            let (row, column) = ((cycle - 1) / 40, (cycle - 1) % 40);
+            let pixel = if x - 1 <= column && column <= x + 1 {
+                '■'
+            } else {
+                ' '
+            };
+
+            rows[row as usize].push(pixel);
+        });
+
+        Some(result.map(|_| Answer::Grid(rows)))
+    }
+}
+
+/// Runs the first `cycles` cycles of the program as it is read, holding only
+/// the current instruction, and calls `during` with every cycle and the value
+/// of X during it.
+fn run_streamed(
+    input: &mut dyn BufRead,
+    cycles: i32,
+    mut during: impl FnMut(i32, i32),
+) -> Result<()> {
+    let mut instructions = parse::read_lines(input);
+    let mut state = State::new(vec![]);
+
+    for cycle in 1..=cycles {
+        if state.instructions.is_empty() {
+            let instruction = instructions.next().context("No more instructions left")??;
+
+            state.instructions.push(instruction);
+        }
+
+        during(cycle, state.x);
+        state.tick()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,303 @@
+use std::{ops::Div, str::FromStr};
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, u64},
+    combinator::{all_consuming, map},
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+    part_1: Some("10605"),
+    part_2: Some("2713310158"),
+}];
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    is_divisible_by: usize,
+    if_true: usize,
+    if_false: usize,
+    inspection_count: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::finish(s, all_consuming(parse_monkey)(s.trim()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Square,
+    Multiply(usize),
+    Add(usize),
+}
+
+impl Operation {
+    fn apply(self, item: usize) -> usize {
+        match self {
+            Operation::Square => item * item,
+            Operation::Multiply(n) => item * n,
+            Operation::Add(n) => item + n,
+        }
+    }
+}
+
+fn parse_operation(s: &str) -> IResult<&str, Operation> {
+    let square = map(tag("* old"), |_| Operation::Square);
+    let multiply = map(preceded(tag("* "), u64), |n| {
+        Operation::Multiply(n as usize)
+    });
+    let add = map(preceded(tag("+ "), u64), |n| Operation::Add(n as usize));
+
+    alt((square, multiply, add))(s)
+}
+
+fn parse_monkey(s: &str) -> IResult<&str, Monkey> {
+    let (s, _) = tuple((tag("Monkey "), u64, tag(":"), line_ending))(s)?;
+    let (s, items) = delimited(
+        tag("  Starting items: "),
+        separated_list1(tag(", "), u64),
+        line_ending,
+    )(s)?;
+    let (s, operation) =
+        delimited(tag("  Operation: new = old "), parse_operation, line_ending)(s)?;
+    let (s, is_divisible_by) = delimited(tag("  Test: divisible by "), u64, line_ending)(s)?;
+    let (s, if_true) = delimited(tag("    If true: throw to monkey "), u64, line_ending)(s)?;
+    let (s, if_false) = preceded(tag("    If false: throw to monkey "), u64)(s)?;
+
+    let monkey = Monkey {
+        items: items.into_iter().map(|item| item as usize).collect(),
+        operation,
+        is_divisible_by: is_divisible_by as usize,
+        if_true: if_true as usize,
+        if_false: if_false as usize,
+        inspection_count: 0,
+    };
+
+    Ok((s, monkey))
+}
+
+struct MonkeyGroup {
+    monkeys: Vec<Monkey>,
+    worry_factor: Box<dyn Fn(usize) -> usize>,
+}
+
+impl MonkeyGroup {
+    fn exec_turn(&mut self, index: usize) -> Result<()> {
+        let monkey = &mut self.monkeys[index];
+
+        let new_items: Vec<_> = monkey
+            .items
+            .iter()
+            .map(|item| {
+                let new_item = (self.worry_factor)(monkey.operation.apply(*item));
+
+                (
+                    if new_item.is_multiple_of(monkey.is_divisible_by) {
+                        monkey.if_true
+                    } else {
+                        monkey.if_false
+                    },
+                    new_item,
+                )
+            })
+            .collect();
+
+        monkey.inspection_count += monkey.items.len();
+        monkey.items = vec![];
+
+        new_items.into_iter().for_each(|(new_index, new_item)| {
+            self.monkeys[new_index].items.push(new_item);
+        });
+
+        Ok(())
+    }
+
+    fn exec_round(&mut self) -> Result<()> {
+        for i in 0..self.monkeys.len() {
+            self.exec_turn(i)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::chunks(input)?)
+    }
+
+    fn compute_1(&self, monkeys: &Self::Input) -> Result<Answer> {
+        let mut monkey_group = MonkeyGroup {
+            monkeys: monkeys.clone(),
+            worry_factor: Box::new(|item| item.div(3)),
+        };
+
+        for _ in 1..=20 {
+            monkey_group.exec_round()?;
+        }
+
+        let mut inspection_counts: Vec<usize> = monkey_group
+            .monkeys
+            .into_iter()
+            .map(|m| m.inspection_count)
+            .collect();
+
+        inspection_counts.sort_unstable();
+        inspection_counts.reverse();
+
+        let answer = inspection_counts[0] * inspection_counts[1];
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, monkeys: &Self::Input) -> Result<Answer> {
+        // Every test only cares about divisibility, so worry levels can be kept
+        // modulo the product of all divisors without changing any outcome
+        let modulus: usize = monkeys.iter().map(|m| m.is_divisible_by).product();
+        let mut monkey_group = MonkeyGroup {
+            monkeys: monkeys.clone(),
+            worry_factor: Box::new(move |item| item % modulus),
+        };
+
+        for _ in 1..=10_000 {
+            monkey_group.exec_round()?;
+        }
+
+        let mut inspection_counts: Vec<usize> = monkey_group
+            .monkeys
+            .into_iter()
+            .map(|m| m.inspection_count)
+            .collect();
+
+        inspection_counts.sort_unstable();
+        inspection_counts.reverse();
+
+        let answer = inspection_counts[0] * inspection_counts[1];
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        // Multiplying monkeys only throw to adding ones and nobody throws to
+        // the squaring one, which keeps part 1's worry levels from overflowing
+        let mut operations = vec![];
+
+        for _ in 0..rng.range(2..=4) {
+            operations.push(Operation::Add(rng.range(1..=8)));
+        }
+
+        for _ in 0..rng.range(1..=3) {
+            operations.push(Operation::Multiply(rng.range(2..=8)));
+        }
+
+        if rng.chance(50) {
+            operations.push(Operation::Square);
+        }
+
+        let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        rng.shuffle(&mut operations);
+        rng.shuffle(&mut divisors);
+
+        let monkeys = operations.len();
+        let mut items = vec![1; monkeys];
+
+        for _ in monkeys..size {
+            items[rng.range(0..=monkeys - 1)] += 1;
+        }
+
+        let chunks: Vec<String> = operations
+            .iter()
+            .enumerate()
+            .map(|(i, operation)| {
+                let targets: Vec<usize> = (0..monkeys)
+                    .filter(|&j| j != i)
+                    .filter(|&j| match (operation, operations[j]) {
+                        (_, Operation::Square) => false,
+                        (Operation::Add(_), _) => true,
+                        (_, target) => matches!(target, Operation::Add(_)),
+                    })
+                    .collect();
+                let if_true = *rng.pick(&targets);
+                let if_false = loop {
+                    let target = *rng.pick(&targets);
+
+                    if target != if_true {
+                        break target;
+                    }
+                };
+                let starting: Vec<String> = (0..items[i])
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect();
+                let operation = match operation {
+                    Operation::Square => "* old".to_string(),
+                    Operation::Multiply(n) => format!("* {n}"),
+                    Operation::Add(n) => format!("+ {n}"),
+                };
+
+                [
+                    format!("Monkey {i}:"),
+                    format!("  Starting items: {}", starting.join(", ")),
+                    format!("  Operation: new = old {operation}"),
+                    format!("  Test: divisible by {}", divisors[i]),
+                    format!("    If true: throw to monkey {if_true}"),
+                    format!("    If false: throw to monkey {if_false}"),
+                ]
+                .join("\n")
+            })
+            .collect();
+
+        Some(chunks.join("\n\n"))
+    }
+}
@@ -0,0 +1,221 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::map,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(usize),
+    List(Vec<Value>),
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.cmp(b),
+            (n @ Value::Number(_), Value::List(_)) => Value::List(vec![n.clone()]).cmp(other),
+            (Value::List(_), n @ Value::Number(_)) => self.cmp(&Value::List(vec![n.clone()])),
+            (Value::List(vec_a), Value::List(vec_b)) => vec_a.cmp(vec_b),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Value, ParseError> {
+        parse::all(s, parse_packet)
+    }
+}
+
+/// A number or a list of packets, e.g. `[1,[2,3],[]]`.
+fn parse_packet(s: &str) -> IResult<&str, Value> {
+    let number = map(parse::unsigned, Value::Number);
+    let list = map(
+        delimited(char('['), parse::comma_list(parse_packet), char(']')),
+        Value::List,
+    );
+
+    alt((number, list))(s)
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    first: Value,
+    second: Value,
+}
+
+fn parse_pair(s: &str) -> IResult<&str, Pair> {
+    map(
+        separated_pair(parse_packet, line_ending, parse_packet),
+        |(first, second)| Pair { first, second },
+    )(s)
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+    part_1: Some("13"),
+    part_2: Some("140"),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::all(input.trim_end(), parse::blocks(parse_pair))?)
+    }
+
+    fn compute_1(&self, pairs: &Self::Input) -> Result<Answer> {
+        let right_pairs: Vec<usize> = pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                if pair.first <= pair.second {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let answer: usize = right_pairs.into_iter().sum();
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, pairs: &Self::Input) -> Result<Answer> {
+        let dividers = dividers();
+        let mut packets: Vec<Value> = pairs
+            .iter()
+            .flat_map(|pair| [pair.first.clone(), pair.second.clone()])
+            .collect();
+
+        packets.extend(dividers.clone());
+        packets.sort();
+
+        let (index_1, _) = packets
+            .iter()
+            .find_position(|p| **p == dividers[0])
+            .context("could not find divider")?;
+        let (index_2, _) = packets
+            .iter()
+            .find_position(|p| **p == dividers[1])
+            .context("could not find divider")?;
+
+        let answer = (index_1 + 1) * (index_2 + 1);
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_2(&self, pairs: &Self::Input) -> Option<Result<Answer>> {
+        let dividers = dividers();
+        let packets: Vec<&Value> = pairs
+            .iter()
+            .flat_map(|pair| [&pair.first, &pair.second])
+            .collect();
+
+        // Counts the packets that go before each divider instead of sorting,
+        // the first divider going before the second
+        let before = |divider| packets.iter().filter(|packet| **packet < divider).count();
+        let answer = (before(&dividers[0]) + 1) * (before(&dividers[1]) + 2);
+
+        Some(Ok(answer.into()))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let first = generate_packet_unlike_dividers(rng);
+                let second = generate_packet_unlike_dividers(rng);
+
+                format!("{first}\n{second}")
+            })
+            .collect();
+
+        Some(pairs.join("\n\n"))
+    }
+}
+
+fn dividers() -> [Value; 2] {
+    [
+        Value::List(vec![Value::List(vec![Value::Number(2)])]),
+        Value::List(vec![Value::List(vec![Value::Number(6)])]),
+    ]
+}
+
+/// A packet that does not compare equal to either divider, as where those
+/// would go is left open by the puzzle.
+fn generate_packet_unlike_dividers(rng: &mut Rng) -> String {
+    loop {
+        let packet = generate_packet(rng, 0);
+        let value: Value = packet.parse().expect("generated packets are valid");
+
+        if dividers()
+            .iter()
+            .all(|divider| value.cmp(divider) != Ordering::Equal)
+        {
+            return packet;
+        }
+    }
+}
+
+fn generate_packet(rng: &mut Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.range(0..=4))
+        .map(|_| {
+            if depth < 4 && rng.chance(30) {
+                generate_packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+
+    format!("[{}]", values.join(","))
+}
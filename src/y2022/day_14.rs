@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    geometry::{Point, Vector},
+    grid::Grid,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+    part_1: Some("24"),
+    part_2: Some("93"),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Vec<Stone>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .flat_map(|line| {
+                line.split(" -> ").tuple_windows().map(|(start, end)| {
+                    let start = parse_coord(input, start)?;
+                    let end = parse_coord(input, end)?;
+
+                    Ok(Stone { start, end })
+                })
+            })
+            .collect()
+    }
+
+    fn compute_1(&self, stones: &Self::Input) -> Result<Answer> {
+        let entry = Point::new(500, 0);
+        let mut cave = Cave::new(stones, entry, false);
+
+        while cave.drop_sand() {}
+
+        let answer = cave.sand;
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, stones: &Self::Input) -> Result<Answer> {
+        let entry = Point::new(500, 0);
+        let mut cave = Cave::new(stones, entry, true);
+
+        while cave.drop_sand() {}
+
+        let answer = cave.sand;
+
+        Ok(answer.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_1(&self, stones: &Self::Input) -> Option<Result<Answer>> {
+        Some(Ok(pour_sand_naive(stones, false).into()))
+    }
+
+    fn reference_2(&self, stones: &Self::Input) -> Option<Result<Answer>> {
+        Some(Ok(pour_sand_naive(stones, true).into()))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        // Rocks stay clear of the entry and close enough to it that the sand
+        // on the floor in part 2 never reaches x = 0
+        let max_y = 20 + size.min(80);
+        let paths: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(450..=550), rng.range(1..=max_y));
+                let mut points = vec![format!("{x},{y}")];
+
+                for i in 0..rng.range(1..=4) {
+                    let length = rng.range(1..=10);
+
+                    if i % 2 == 0 {
+                        x = if rng.chance(50) {
+                            x + length
+                        } else {
+                            x - length
+                        };
+                    } else {
+                        y = if rng.chance(50) {
+                            (y + length).min(max_y)
+                        } else {
+                            y.saturating_sub(length).max(1)
+                        };
+                    }
+
+                    points.push(format!("{x},{y}"));
+                }
+
+                points.join(" -> ")
+            })
+            .collect();
+
+        Some(paths.join("\n"))
+    }
+}
+
+/// Where a grain of sand tries to fall to, in order.
+const FALLS: [Vector; 3] = [Vector::new(0, 1), Vector::new(-1, 1), Vector::new(1, 1)];
+
+fn parse_coord(source: &str, s: &str) -> Result<Point, ParseError> {
+    let (x, y) = parse::split_once(s, ",").map_err(|err| err.within(source, s))?;
+
+    // Rock is never above the sand's entry at y=0 nor left of x=0, so the
+    // coordinates are read unsigned
+    let x: u32 = parse::number(source, x)?;
+    let y: u32 = parse::number(source, y)?;
+
+    Ok(Point::new(x.into(), y.into()))
+}
+
+#[derive(Debug, Clone)]
+pub struct Stone {
+    start: Point,
+    end: Point,
+}
+
+impl Stone {
+    /// Every point the stone covers.
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let (start, end) = (self.start, self.end);
+
+        (start.x().min(end.x())..=start.x().max(end.x()))
+            .cartesian_product(start.y().min(end.y())..=start.y().max(end.y()))
+            .map(|(x, y)| Point::new(x, y))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The part of the cave that sand can reach, stored in a grid where the cell
+/// of point `p` is at `p - corner`.
+#[derive(Debug)]
+struct Cave {
+    cells: Grid<Cell>,
+    corner: Vector,
+    sand_entry: Point,
+    sand: usize,
+    bottom_y: i64,
+    has_floor: bool,
+}
+
+impl Cave {
+    fn new(stones: &[Stone], sand_entry: Point, has_floor: bool) -> Self {
+        let bottom_y = stones
+            .iter()
+            .map(|stone| stone.start.y().max(stone.end.y()))
+            .max()
+            .unwrap_or_default();
+
+        // Sand moves at most one column sideways for every row it falls, so
+        // it never gets further from the entry than the floor is deep
+        let reach = bottom_y + 2;
+        let left = stones
+            .iter()
+            .map(|stone| stone.start.x().min(stone.end.x()))
+            .chain([sand_entry.x() - reach])
+            .min()
+            .unwrap_or_default();
+        let right = stones
+            .iter()
+            .map(|stone| stone.start.x().max(stone.end.x()))
+            .chain([sand_entry.x() + reach])
+            .max()
+            .unwrap_or_default();
+
+        // The row below the lowest rock is where sand either falls out or,
+        // with a floor, comes to rest
+        let width = (right - left + 1) as usize;
+        let mut cave = Self {
+            cells: Grid::new(width, bottom_y as usize + 2, Cell::Air),
+            corner: Vector::new(left, 0),
+            sand_entry,
+            sand: 0,
+            bottom_y,
+            has_floor,
+        };
+
+        for point in stones.iter().flat_map(Stone::points) {
+            *cave.cell_mut(point).expect("the grid covers every rock") = Cell::Rock;
+        }
+
+        cave
+    }
+
+    fn cell(&self, point: Point) -> Option<Cell> {
+        self.cells.get((point - self.corner).position()?).copied()
+    }
+
+    fn cell_mut(&mut self, point: Point) -> Option<&mut Cell> {
+        self.cells.get_mut((point - self.corner).position()?)
+    }
+
+    fn valid_move(&self, from: Point) -> Option<Point> {
+        FALLS
+            .into_iter()
+            .map(|fall| from + fall)
+            .find(|&next| self.cell(next) == Some(Cell::Air))
+    }
+
+    fn drop_sand(&mut self) -> bool {
+        let mut sand = self.sand_entry;
+
+        if self.cell(sand) == Some(Cell::Sand) {
+            // No more sand can fit
+            return false;
+        }
+
+        while let Some(next_pos) = self.valid_move(sand) {
+            if self.has_floor || next_pos.y() <= self.bottom_y {
+                sand = next_pos;
+            } else {
+                // Sand fell out
+                return false;
+            }
+        }
+
+        if let Some(cell) = self.cell_mut(sand) {
+            *cell = Cell::Sand;
+            self.sand += 1;
+        }
+
+        true
+    }
+}
+
+/// Drops sand one cell at a time onto every cell of rock, without any of
+/// `Cave`'s bookkeeping, and counts the grains that come to rest.
+fn pour_sand_naive(stones: &[Stone], has_floor: bool) -> usize {
+    let mut blocked: HashSet<Point> = HashSet::new();
+
+    for Stone { start, end } in stones {
+        for x in start.x().min(end.x())..=start.x().max(end.x()) {
+            for y in start.y().min(end.y())..=start.y().max(end.y()) {
+                blocked.insert(Point::new(x, y));
+            }
+        }
+    }
+
+    let bottom = blocked
+        .iter()
+        .map(|point| point.y())
+        .max()
+        .unwrap_or_default();
+    let rocks = blocked.len();
+
+    let entry = Point::new(500, 0);
+
+    'grains: while !blocked.contains(&entry) {
+        let mut grain = entry;
+
+        loop {
+            if !has_floor && grain.y() > bottom {
+                break 'grains;
+            }
+
+            if has_floor && grain.y() + 1 == bottom + 2 {
+                break;
+            }
+
+            match FALLS
+                .into_iter()
+                .map(|fall| grain + fall)
+                .find(|next| !blocked.contains(next))
+            {
+                Some(next) => grain = next,
+                None => break,
+            }
+        }
+
+        blocked.insert(grain);
+    }
+
+    blocked.len() - rocks
+}
@@ -0,0 +1,370 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{ensure, Context, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    graph,
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+    part_1: Some("1651"),
+    part_2: Some("1707"),
+}];
+
+/// Opening the valves is tried in every order, so generated caves never have
+/// more valves with a flow rate than this, however many valves they have.
+const MAX_GENERATED_FLOWS: usize = 15;
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = Cave;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn compute_1(&self, cave: &Self::Input) -> Result<Answer> {
+        let answer = cave.release_pressure("AA", 30)?;
+
+        Ok(answer.into())
+    }
+
+    fn compute_2(&self, _cave: &Self::Input) -> Result<Answer> {
+        todo!()
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn reference_1(&self, cave: &Self::Input) -> Option<Result<Answer>> {
+        Some(Ok(release_pressure_naive(cave, "AA", 30).into()))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        let valves = size.clamp(2, 26 * 26);
+        let mut ids = vec!["AA".to_string()];
+
+        while ids.len() < valves {
+            let id: String = (0..2)
+                .map(|_| char::from(b'A' + rng.range(0..=25) as u8))
+                .collect();
+
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+
+        // Tunnels go both ways, and joining every valve to an earlier one
+        // keeps the cave connected
+        let mut tunnels: Vec<Vec<usize>> = vec![vec![]; valves];
+
+        for valve in 1..valves {
+            for _ in 0..rng.range(1..=2) {
+                let other = rng.range(0..=valve - 1);
+
+                if !tunnels[valve].contains(&other) {
+                    tunnels[valve].push(other);
+                    tunnels[other].push(valve);
+                }
+            }
+        }
+
+        // About half of the valves other than the start get a flow rate
+        let mut flowing: Vec<usize> = (1..valves).collect();
+
+        rng.shuffle(&mut flowing);
+        flowing.truncate((valves / 2).min(MAX_GENERATED_FLOWS));
+
+        let mut lines: Vec<String> = tunnels
+            .iter()
+            .enumerate()
+            .map(|(valve, tunnels)| {
+                let flow_rate = if flowing.contains(&valve) {
+                    rng.range(1..=25)
+                } else {
+                    0
+                };
+                let to: Vec<&str> = tunnels.iter().map(|&other| ids[other].as_str()).collect();
+                let tunnels = match to.as_slice() {
+                    [only] => format!("tunnel leads to valve {only}"),
+                    to => format!("tunnels lead to valves {}", to.join(", ")),
+                };
+
+                format!("Valve {} has flow rate={flow_rate}; {tunnels}", ids[valve])
+            })
+            .collect();
+
+        rng.shuffle(&mut lines);
+
+        Some(lines.join("\n"))
+    }
+}
+
+type Id = String;
+
+#[derive(Debug)]
+struct Valve {
+    flow_rate: usize,
+}
+
+impl Valve {
+    fn new(flow_rate: usize) -> Self {
+        Self { flow_rate }
+    }
+}
+
+#[derive(Debug)]
+pub struct Cave {
+    valves: HashMap<Id, Valve>,
+    tunnels: HashMap<Id, Vec<Id>>,
+}
+
+impl Cave {
+    fn new(valves: HashMap<Id, Valve>, tunnels: HashMap<Id, Vec<Id>>) -> Self {
+        Self { valves, tunnels }
+    }
+
+    /// The most pressure that can be released in `minutes` from `start`,
+    /// going the shortest way from one valve worth opening to the next.
+    fn release_pressure(&self, start: &str, minutes: usize) -> Result<usize> {
+        let start = self
+            .valves
+            .get_key_value(start)
+            .with_context(|| format!("There is no valve {start}"))?
+            .0;
+
+        // The valves worth opening come first so that they are numbered from
+        // 0 in the distances between the valves, and the start is wherever
+        // its flow rate puts it
+        let mut valves: Vec<&Id> = self
+            .valves
+            .iter()
+            .filter(|(_, valve)| valve.flow_rate > 0)
+            .map(|(id, _)| id)
+            .collect();
+        let useful = valves.len();
+
+        ensure!(useful <= 64, "Too many valves with a flow rate: {useful}");
+
+        valves.extend(
+            self.valves
+                .iter()
+                .filter(|(_, valve)| valve.flow_rate == 0)
+                .map(|(id, _)| id),
+        );
+
+        let from = valves
+            .iter()
+            .position(|&id| id == start)
+            .expect("the start is one of the valves");
+        let valves: Vec<Id> = valves.into_iter().cloned().collect();
+        let distances = graph::floyd_warshall(&self.tunnels, &valves);
+        let flow_rates: Vec<usize> = valves[..useful]
+            .iter()
+            .map(|id| self.valves[id].flow_rate)
+            .collect();
+
+        Ok(most_pressure(&distances, &flow_rates, from, minutes, 0))
+    }
+}
+
+/// The most pressure that can be released by opening the valves of
+/// `flow_rates` that are not `open` yet, starting at valve `from`.
+fn most_pressure(
+    distances: &[Vec<Option<usize>>],
+    flow_rates: &[usize],
+    from: usize,
+    time_left: usize,
+    open: u64,
+) -> usize {
+    (0..flow_rates.len())
+        .filter(|valve| open & (1 << valve) == 0)
+        .filter_map(|valve| {
+            let time_left = time_left.checked_sub(distances[from][valve]? + 1)?;
+            let rest = most_pressure(distances, flow_rates, valve, time_left, open | 1 << valve);
+
+            Some(flow_rates[valve] * time_left + rest)
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut valves = HashMap::new();
+        let mut tunnels = HashMap::new();
+
+        for line in s.lines() {
+            let (id, valve, leads_to) =
+                parse::all(line, parse_valve).map_err(|err| err.within(s, line))?;
+
+            valves.insert(id.clone(), valve);
+            tunnels.insert(id, leads_to);
+        }
+
+        Ok(Self::new(valves, tunnels))
+    }
+}
+
+/// A valve, e.g. `Valve BB has flow rate=13; tunnels lead to valves CC, AA`.
+fn parse_valve(s: &str) -> IResult<&str, (Id, Valve, Vec<Id>)> {
+    let id = || {
+        map(
+            take_while_m_n(2, 2, |c: char| c.is_ascii_uppercase()),
+            String::from,
+        )
+    };
+    let leads_to = alt((
+        tag("; tunnels lead to valves "),
+        tag("; tunnel leads to valve "),
+    ));
+
+    tuple((
+        preceded(tag("Valve "), id()),
+        map(
+            preceded(tag(" has flow "), parse::key_value("rate", parse::unsigned)),
+            Valve::new,
+        ),
+        preceded(leads_to, parse::comma_list(id())),
+    ))(s)
+}
+
+/// A walk through the cave, one minute at a time.
+#[derive(Debug, Clone)]
+struct Run {
+    current: Id,
+    is_open: Vec<Id>,
+    acc_flow: usize,
+    time_left: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Move {
+    Go { to: Id },
+    OpenValve { flow_rate: usize },
+}
+
+impl Run {
+    fn new(start: Id, minutes: usize) -> Self {
+        Self {
+            current: start,
+            is_open: vec![],
+            acc_flow: 0,
+            time_left: minutes,
+        }
+    }
+
+    fn valid_moves(&self, cave: &Cave) -> Vec<Move> {
+        let mut moves = vec![];
+
+        match cave.valves.get(&self.current) {
+            Some(&Valve { flow_rate })
+                if flow_rate > 0 && !self.is_open.contains(&self.current) =>
+            {
+                moves.push(Move::OpenValve { flow_rate });
+            }
+            _ => {}
+        }
+
+        for to in cave.tunnels.get(&self.current).into_iter().flatten() {
+            moves.push(Move::Go { to: to.clone() });
+        }
+
+        moves
+    }
+
+    fn make_move(&mut self, mmove: Move) {
+        self.time_left -= 1;
+
+        match mmove {
+            Move::Go { to } => self.current = to,
+            Move::OpenValve { flow_rate } => {
+                self.is_open.push(self.current.clone());
+                self.acc_flow += flow_rate * self.time_left;
+            }
+        }
+    }
+}
+
+/// Keeps only the best run for every combination of position and open valves,
+/// as the rest can never catch up with it.
+fn prune(runs: Vec<Run>) -> Vec<Run> {
+    let mut best: HashMap<(Id, Vec<Id>), Run> = HashMap::new();
+
+    for run in runs {
+        let mut is_open = run.is_open.clone();
+        is_open.sort_unstable();
+        let key = (run.current.clone(), is_open);
+
+        match best.get(&key) {
+            Some(other) if other.acc_flow >= run.acc_flow => {}
+            _ => {
+                best.insert(key, run);
+            }
+        }
+    }
+
+    best.into_values().collect()
+}
+
+/// Simulates every walk through the cave minute by minute, either moving
+/// through a tunnel or opening the valve at hand, without knowing anything
+/// about the distances between the valves.
+fn release_pressure_naive(cave: &Cave, start: &str, minutes: usize) -> usize {
+    let mut runs = vec![Run::new(start.to_string(), minutes)];
+
+    for _ in 0..minutes {
+        runs = runs
+            .into_iter()
+            .flat_map(|run| {
+                let moves = run.valid_moves(cave);
+
+                if moves.is_empty() {
+                    // A run that is stuck keeps the flow it has already
+                    // accumulated, so it still competes for the answer
+                    return vec![run];
+                }
+
+                moves
+                    .into_iter()
+                    .map(|mmove| {
+                        let mut run = run.clone();
+                        run.make_move(mmove);
+                        run
+                    })
+                    .collect()
+            })
+            .collect();
+
+        runs = prune(runs);
+    }
+
+    runs.iter()
+        .map(|run| run.acc_flow)
+        .max()
+        .unwrap_or_default()
+}
@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use nom::{
+    branch::alt,
+    character::complete::{char, u64},
+    combinator::{all_consuming, map},
+    multi::many1,
+    IResult,
+};
+
+use crate::{
+    answer::Answer,
+    generate::Rng,
+    geometry::Direction,
+    grid::{Grid, Position},
+    parse::{self, ParseError},
+    solution::{Example, Solution},
+};
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5",
+    part_1: Some("6032"),
+    part_2: Some("5031"),
+}];
+
+pub struct Day {}
+impl Solution for Day {
+    type Input = (Map, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        let (map, instructions) = parse::split_once(input, "\n\n")?;
+
+        let map: Map = parse::within(input, map)?;
+        let instructions: Vec<Instruction> =
+            parse_instructions(instructions).map_err(|err| err.within(input, instructions))?;
+
+        Ok((map, instructions))
+    }
+
+    fn compute_1(&self, (map, instructions): &Self::Input) -> anyhow::Result<Answer> {
+        let position = (
+            0_usize,
+            map.0
+                .row(0)
+                .iter()
+                .position(|t| *t == Tile::Path)
+                .context("no empty path found")?,
+        );
+        let person = Person {
+            position,
+            direction: Direction::Right,
+        };
+
+        let _person = instructions
+            .iter()
+            .copied()
+            .fold(person, apply_instruction(map));
+
+        Ok(String::new().into())
+    }
+
+    fn compute_2(&self, _input: &Self::Input) -> anyhow::Result<Answer> {
+        todo!()
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        // The faces are laid out in the same net as in the example
+        const NET: [&str; 3] = ["  x ", "xxx ", "  xx"];
+
+        let side = size.clamp(1, 50);
+        let mut lines: Vec<String> = (0..3 * side)
+            .map(|row| {
+                let faces = NET[row / side];
+                let line: String = faces
+                    .chars()
+                    .flat_map(|face| (0..side).map(move |_| face))
+                    .map(|face| match face {
+                        'x' if rng.chance(10) => '#',
+                        'x' => '.',
+                        _ => ' ',
+                    })
+                    .collect();
+
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        // The path starts on the leftmost open tile of the top row
+        lines[0].replace_range(2 * side..2 * side + 1, ".");
+        lines.push(String::new());
+
+        let mut path = rng.range(1..=50).to_string();
+
+        for _ in 0..size.max(1) {
+            path.push(*rng.pick(&['L', 'R']));
+            path.push_str(&rng.range(1..=50).to_string());
+        }
+
+        lines.push(path);
+
+        Some(lines.join("\n"))
+    }
+}
+
+fn apply_instruction(_map: &Map) -> impl Fn(Person, Instruction) -> Person {
+    |person: Person, instruction: Instruction| match instruction {
+        Instruction::Walk(steps) => todo!("walk {steps} steps"),
+        rotate => Person {
+            position: person.position,
+            direction: rotate.turn(person.direction),
+        },
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Person {
+    position: Position,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Path,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Walk(usize),
+    RotateLeft,
+    RotateRight,
+}
+
+impl Instruction {
+    fn turn(self, direction: Direction) -> Direction {
+        match self {
+            Self::RotateLeft => direction.turn_left(),
+            Self::RotateRight => direction.turn_right(),
+            Self::Walk(_) => direction,
+        }
+    }
+}
+
+/// The board, with the rows shorter than the widest one padded with empty
+/// tiles.
+#[derive(Debug)]
+pub struct Map(Grid<Tile>);
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse_ragged(s, "a tile", Tile::Empty, parse_tile)?;
+
+        Ok(Self(map))
+    }
+}
+
+fn parse_tile(c: char) -> Option<Tile> {
+    use Tile::*;
+
+    match c {
+        ' ' => Some(Empty),
+        '#' => Some(Wall),
+        '.' => Some(Path),
+        _ => None,
+    }
+}
+
+fn parse_instructions(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::finish(s, all_consuming(many1(parse_instruction))(s.trim()))
+}
+
+fn parse_instruction(s: &str) -> IResult<&str, Instruction> {
+    use Instruction::*;
+
+    let walk = map(u64, |d: u64| Walk(d as usize));
+    let left = map(char('L'), |_| RotateLeft);
+    let right = map(char('R'), |_| RotateRight);
+
+    alt((walk, left, right))(s)
+}
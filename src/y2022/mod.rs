@@ -0,0 +1,35 @@
+use crate::registry;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_13;
+mod day_14;
+mod day_16;
+mod day_22;
+
+registry::days! {
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+    day_06,
+    day_07,
+    day_08,
+    day_09,
+    day_10,
+    day_11,
+    day_13,
+    day_14,
+    day_16,
+    day_22,
+}
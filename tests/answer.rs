@@ -0,0 +1,18 @@
+use aoc_2022::Answer;
+
+#[test]
+fn answers_compare_to_text() {
+    assert_eq!(Answer::from(74711_usize), "74711");
+    assert_eq!(Answer::from(-3_i32), " -3\n");
+    assert_eq!(Answer::from("TDCHVHJTG"), "TDCHVHJTG");
+    assert_ne!(Answer::from(1_u32), "2");
+}
+
+#[test]
+fn grids_ignore_surrounding_blank_lines_and_trailing_spaces() {
+    let grid = Answer::grid([" ■ ", "■ ■"]);
+
+    assert_eq!(grid.to_string(), " ■ \n■ ■");
+    assert_eq!(grid, "\n ■\n■ ■  \n");
+    assert_ne!(grid, "■\n■ ■");
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use aoc_2022::api::Client;
+use aoc_2022::ledger::{Check, Ledger, Verdict};
+use aoc_2022::runner::Part;
+use common::temp_dir;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// Serves a single canned HTTP response on a local port and hands back the
+/// request head it received.
+fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request = String::new();
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+            request.push_str(&line);
+        }
+
+        stream.write_all(response.as_bytes()).unwrap();
+        request
+    });
+
+    (base_url, handle)
+}
+
+#[test]
+fn missing_input_is_downloaded_and_cached() {
+    let dir = temp_dir("input-cache");
+    let (base_url, server) = serve_once("200 OK", "1\n2\n3\n");
+
+    let input =
+        aoc_2022::input::read_cached(&dir, 2022, "07", || Ok(Client::new(&base_url, "abc")))
+            .unwrap();
+    assert_eq!(input, "1\n2\n3\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2022/day/7/input "));
+    assert!(request.contains("session=abc"));
+    assert!(dir.join("2022").join("07").exists());
+
+    let input =
+        aoc_2022::input::read_cached(&dir, 2022, "07", || panic!("downloaded twice")).unwrap();
+    assert_eq!(input, "1\n2\n3\n");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_download_is_not_cached() {
+    let dir = temp_dir("input-failure");
+    let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+
+    let result =
+        aoc_2022::input::read_cached(&dir, 2022, "07", || Ok(Client::new(&base_url, "abc")));
+    assert!(result.is_err());
+    assert!(!dir.join("2022").join("07").exists());
+
+    server.join().unwrap();
+}
+
+#[test]
+fn ledger_refuses_known_answers() {
+    let dir = temp_dir("ledger");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("ledger.json");
+
+    let mut ledger = Ledger::open(&path).unwrap();
+    ledger
+        .record(2022, "01", Part::One, "100", Verdict::TooHigh)
+        .unwrap();
+    ledger
+        .record(2022, "01", Part::One, "10", Verdict::TooLow)
+        .unwrap();
+    ledger
+        .record(2022, "01", Part::One, "42", Verdict::Wrong)
+        .unwrap();
+
+    let ledger = Ledger::open(&path).unwrap();
+    assert_eq!(
+        ledger.check(2022, "01", Part::One, "42"),
+        Check::KnownWrong(Verdict::Wrong)
+    );
+    assert!(matches!(
+        ledger.check(2022, "01", Part::One, "150"),
+        Check::OutOfBounds(_)
+    ));
+    assert!(matches!(
+        ledger.check(2022, "01", Part::One, "5"),
+        Check::OutOfBounds(_)
+    ));
+    assert_eq!(ledger.check(2022, "01", Part::One, "50"), Check::New);
+    assert_eq!(ledger.check(2022, "01", Part::Two, "42"), Check::New);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn submitted_answer_verdicts() {
+    let page = "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    let (base_url, server) = serve_once("200 OK", page);
+
+    let verdict = Client::new(&base_url, "abc")
+        .submit_answer(2022, "07", Part::Two, "1234")
+        .unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /2022/day/7/answer "));
+    assert!(request.contains("session=abc"));
+}
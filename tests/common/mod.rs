@@ -0,0 +1,10 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// A directory for a test to write into, emptied of what an earlier run left.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-2022-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    dir
+}
@@ -0,0 +1,82 @@
+use aoc_2022::generate::Rng;
+use aoc_2022::registry;
+use aoc_2022::runner::{self, Differential, Part, Status};
+
+#[test]
+fn generated_inputs_are_valid() {
+    for year in registry::all_years() {
+        for entry in year.days {
+            for seed in 0..20 {
+                let Some(input) = entry.solution.generate(&mut Rng::new(seed), 8) else {
+                    continue;
+                };
+
+                for (part, outcome) in
+                    Part::ALL
+                        .into_iter()
+                        .zip(runner::run_parts(entry.solution, &Part::ALL, &input))
+                {
+                    if let Status::Error(err) = outcome.status {
+                        panic!(
+                            "Day {} of {} part {part} failed on seed {seed}: {err:#}\n{input}",
+                            entry.day(),
+                            year.year(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_depend_on_the_seed() {
+    let solution = registry::get(2022, "13").unwrap();
+    let generate = |seed| solution.generate(&mut Rng::new(seed), 10).unwrap();
+
+    assert_eq!(generate(1), generate(1));
+    assert_ne!(generate(1), generate(2));
+}
+
+#[test]
+fn solutions_agree_with_their_references() {
+    for year in registry::all_years() {
+        for entry in year.days {
+            match runner::differential(entry.solution, 0, 30, 12) {
+                Ok(Differential::Agreed | Differential::NothingToCompare(_)) => {}
+                Ok(Differential::Diverged(divergence)) => {
+                    panic!("Day {} of {}: {divergence}", entry.day(), year.year())
+                }
+                Err(err) => panic!("Day {} of {}: {err:#}", entry.day(), year.year()),
+            }
+        }
+    }
+}
+
+#[test]
+fn references_solve_the_examples() {
+    let solution = registry::get(2022, "14").unwrap();
+    let example = &solution.examples()[0];
+    let input = solution.parse(example.input).unwrap();
+
+    let answer = solution.reference_2(input.as_ref()).unwrap().unwrap();
+
+    assert_eq!(Some(answer.to_string().as_str()), example.part_2);
+}
+
+#[test]
+fn references_open_a_start_valve_with_a_flow_rate() {
+    let solution = registry::get(2022, "16").unwrap();
+    let input = solution
+        .parse(
+            "Valve AA has flow rate=5; tunnels lead to valves BB\n\
+             Valve BB has flow rate=10; tunnels lead to valves AA",
+        )
+        .unwrap();
+
+    let expected = solution.reference_1(input.as_ref()).unwrap().unwrap();
+    let actual = solution.compute_1(input.as_ref()).unwrap();
+
+    assert_eq!(expected.to_string(), "415");
+    assert_eq!(actual.to_string(), expected.to_string());
+}
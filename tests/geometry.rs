@@ -0,0 +1,39 @@
+use aoc_2022::geometry::{Direction, Point, Vector};
+
+#[test]
+fn directions_turn() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.reverse().delta(), -direction.delta());
+    }
+
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(
+        Point::new(2, 2) + Direction::Up.delta() * 2,
+        Point::new(2, 0)
+    );
+}
+
+#[test]
+fn distances_in_2d_and_3d() {
+    let (a, b) = (Point::new(1, -2), Point::new(-3, 1));
+
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(b - a, Vector::new(-4, 3));
+    assert_eq!((b - a).signum(), Vector::new(-1, 1));
+
+    let (a, b) = (Point([1, 2, 3]), Point([3, 2, -1]));
+
+    assert_eq!(a.manhattan(b), 6);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a + (b - a), b);
+}
+
+#[test]
+fn points_and_grid_positions() {
+    assert_eq!(Point::new(3, 1).position(), Some((1, 3)));
+    assert_eq!(Point::new(-1, 1).position(), None);
+    assert_eq!(Point::from((1, 3)), Point::new(3, 1));
+}
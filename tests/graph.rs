@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use aoc_2022::{
+    graph::{self, Graph, GridGraph},
+    grid::Grid,
+};
+
+fn tunnels() -> HashMap<&'static str, Vec<&'static str>> {
+    HashMap::from([
+        ("a", vec!["b", "c"]),
+        ("b", vec!["a", "d"]),
+        ("c", vec!["a", "d"]),
+        ("d", vec!["b", "c"]),
+        ("e", vec![]),
+    ])
+}
+
+/// Steps of 1 or 3 along the numbers from 0 to 10, where a step of 3 costs 2.
+struct Line;
+
+impl Graph for Line {
+    type Node = u32;
+
+    fn edges(&self, &node: &u32) -> impl Iterator<Item = (u32, usize)> {
+        [(node + 1, 1), (node + 3, 2)]
+            .into_iter()
+            .filter(|&(next, _)| next <= 10)
+    }
+}
+
+#[test]
+fn bfs_counts_edges() {
+    let distances = graph::bfs(&tunnels(), ["a"]);
+
+    assert_eq!(distances["d"], 2);
+    assert!(!distances.contains_key("e"));
+    assert_eq!(graph::bfs(&Line, [0])[&10], 4);
+}
+
+#[test]
+fn cheapest_ways_agree() {
+    let costs = graph::dijkstra(&Line, [0]);
+    let all_pairs = graph::floyd_warshall(&Line, &(0..=10).collect::<Vec<_>>());
+
+    assert_eq!(costs[&10], 7);
+    assert_eq!(
+        graph::a_star(&Line, 0, &10, |&n| (10 - n as usize) / 2),
+        Some(7)
+    );
+    assert_eq!(graph::a_star(&Line, 5, &2, |_| 0), None);
+
+    for (to, cost) in costs {
+        assert_eq!(all_pairs[0][to as usize], Some(cost));
+    }
+}
+
+#[test]
+fn grids_are_graphs() {
+    let grid = Grid::parse("..#\n#..\n...", "a tile", Some).unwrap();
+    let open = GridGraph::new(&grid, |_, &to| (to == '.').then_some(1));
+    let manhattan = |&(row, column): &(usize, usize)| (2 - row) + (2 - column);
+
+    assert_eq!(graph::a_star(&open, (0, 0), &(2, 2), manhattan), Some(4));
+    assert_eq!(graph::dijkstra(&open, [(0, 0)]).len(), 7);
+}
+
+#[test]
+fn components_split_the_graph() {
+    let tunnels = tunnels();
+    let components = graph::connected_components(&tunnels, &["e", "a", "b"]);
+
+    assert_eq!(components, [vec!["e"], vec!["a", "b", "c", "d"]]);
+}
@@ -0,0 +1,87 @@
+use aoc_2022::{
+    geometry::{Direction, Vector},
+    grid::Grid,
+};
+
+fn digits(s: &str) -> Grid<u32> {
+    Grid::parse(s, "a digit", |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn cells_are_stored_row_by_row() {
+    let grid = digits("123\n456");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 4);
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+    assert_eq!(grid.to_string(), "123\n456");
+}
+
+#[test]
+fn positions_outside_the_grid_are_refused() {
+    let mut grid = digits("123\n456");
+
+    assert_eq!(grid.get_mut((usize::MAX, 0)), None);
+    assert_eq!(grid.get_mut((0, 3)), None);
+
+    *grid.get_mut((1, 2)).unwrap() = 0;
+    assert_eq!(grid[(1, 2)], 0);
+}
+
+#[test]
+#[should_panic(expected = "column 3 is outside the grid")]
+fn columns_outside_the_grid_panic() {
+    digits("123\n456").column(3).count();
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = digits("123\n456\n789");
+
+    assert_eq!(
+        grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+    assert_eq!(
+        grid.neighbours_8((2, 2)).collect::<Vec<_>>(),
+        [(1, 1), (1, 2), (2, 1)]
+    );
+    assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+}
+
+#[test]
+fn rays_run_to_the_edge() {
+    let grid = digits("123\n456\n789");
+
+    assert_eq!(
+        grid.ray((2, 0), Vector::new(1, -1))
+            .map(|p| grid[p])
+            .collect::<Vec<_>>(),
+        [5, 3]
+    );
+    assert_eq!(grid.ray((0, 1), Direction::Up.delta()).count(), 0);
+}
+
+#[test]
+fn ragged_rows_are_padded() {
+    let grid = Grid::parse_ragged("  #\n#\n", "a tile", ' ', Some).unwrap();
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.row(1), &['#', ' ', ' ']);
+    assert_eq!(grid.to_string(), "  #\n#  ");
+}
+
+#[test]
+fn parse_errors_point_at_the_cell() {
+    let err = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+
+    assert_eq!((err.line(), err.column()), (2, 2));
+    assert_eq!(err.message(), "'x' is not a digit");
+
+    let err = Grid::parse("12\n345", "a digit", |c| c.to_digit(10)).unwrap_err();
+
+    assert_eq!(err.message(), "Expected a row of 2 characters");
+}
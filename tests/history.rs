@@ -0,0 +1,53 @@
+mod common;
+
+use aoc_2022::history::{BenchRun, History, Step};
+use common::temp_dir;
+use std::fs;
+
+fn bench_run(baseline: Option<&str>, parse_ms: f64, part_ms: f64) -> BenchRun {
+    let step = |step: &str, median_ms| Step {
+        step: step.to_string(),
+        runs: 1,
+        min_ms: median_ms,
+        median_ms,
+        max_ms: median_ms,
+    };
+
+    BenchRun::new(
+        2022,
+        "14",
+        baseline.map(ToString::to_string),
+        vec![step("parse", parse_ms), step("01", part_ms)],
+    )
+}
+
+#[test]
+fn bench_history_flags_regressions() {
+    let dir = temp_dir("history");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("bench.json");
+
+    let mut history = History::open(&path).unwrap();
+    history
+        .record(bench_run(Some("before"), 1.0, 10.0))
+        .unwrap();
+    history.record(bench_run(None, 1.0, 20.0)).unwrap();
+
+    let history = History::open(&path).unwrap();
+    let run = bench_run(None, 1.05, 11.5);
+
+    let previous = history.previous(&run, None).unwrap();
+    let changes = run.compare(previous, 10.0);
+    assert_eq!(changes.len(), 2);
+    assert!(changes.iter().all(|change| !change.regressed));
+    assert_eq!(changes[1].percent().round(), -43.0);
+
+    let baseline = history.previous(&run, Some("before")).unwrap();
+    let changes = run.compare(baseline, 10.0);
+    assert!(!changes[0].regressed);
+    assert!(changes[1].regressed);
+
+    assert!(history.previous(&run, Some("after")).is_none());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
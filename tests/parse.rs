@@ -0,0 +1,105 @@
+use aoc_2022::{
+    parse::{self, ParseError},
+    solve, Part,
+};
+
+#[test]
+fn errors_point_into_the_whole_input() {
+    let err = solve(2022, "04", Part::One, "2-4,6-8\n2-x,6-8\n").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 3));
+    assert_eq!(err.text(), "2-x,6-8");
+    assert_eq!(
+        err.to_string(),
+        "\"x\" is not a number at line 2, column 3\n2 | 2-x,6-8\n  |   ^"
+    );
+}
+
+#[test]
+fn both_columns_of_the_strategy_guide_are_checked() {
+    let err = solve(2022, "02", Part::Two, "A Y\nB Q\n").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 3));
+    assert_eq!(err.message(), "\"Q\" is not a hand");
+
+    let err = solve(2022, "02", Part::One, "A Y\nD X\n").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 1));
+}
+
+#[test]
+fn rock_above_the_sand_entry_is_refused() {
+    let err = solve(2022, "14", Part::One, "498,-4 -> 498,6\n").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (1, 5));
+    assert_eq!(err.message(), "\"-4\" is not a number");
+}
+
+#[test]
+fn carets_cover_the_span() {
+    let source = "Valve AA has flow rate=0\nValve BB has flow rate=1x";
+    let err = ParseError::at(source, &source[48..], "Not a number");
+
+    assert_eq!((err.line(), err.column()), (2, 24));
+    assert!(err
+        .to_string()
+        .ends_with(&format!("\n  | {}^^", " ".repeat(23))));
+}
+
+#[test]
+fn errors_at_the_end_of_the_input() {
+    let err = solve(2022, "22", Part::One, "...#\n\n10R5X").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (3, 5));
+    assert_eq!(err.text(), "10R5X");
+}
+
+#[test]
+fn combinators_read_numbers_and_lists() {
+    assert_eq!(parse::all("42", parse::unsigned::<u32>), Ok(42));
+    assert_eq!(parse::all("-42", parse::signed::<i32>), Ok(-42));
+    assert_eq!(
+        parse::all("1,2, 3", parse::comma_list(parse::unsigned::<u8>)),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        parse::all("rate=13", parse::key_value("rate", parse::unsigned::<u8>)),
+        Ok(13)
+    );
+    assert_eq!(
+        parse::all(
+            "1,-2\n\n3",
+            parse::blocks(parse::comma_list(parse::signed::<i8>))
+        ),
+        Ok(vec![vec![1, -2], vec![3]])
+    );
+}
+
+#[test]
+fn combinator_errors_point_into_the_input() {
+    let err = parse::all("1,2,x", parse::comma_list(parse::unsigned::<u8>)).unwrap_err();
+
+    assert_eq!((err.line(), err.column()), (1, 4));
+
+    let err = solve(2022, "16", Part::One, "Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=x; tunnel leads to valve AA").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 24));
+}
+
+#[test]
+fn char_grids_end_at_a_blank_line() {
+    let (rest, grid) = parse::char_grid(' ', Some)(" #\n##\n\nrest").unwrap();
+
+    assert_eq!(rest, "\n\nrest");
+    assert_eq!(grid.to_string(), " #\n##");
+
+    let err = parse::all("..\n.x", parse::char_grid('.', |c| (c == '.').then_some(c))).unwrap_err();
+
+    assert_eq!((err.line(), err.column()), (2, 2));
+}
@@ -0,0 +1,121 @@
+use anyhow::bail;
+use aoc_2022::registry;
+use aoc_2022::runner::{self, Part, Status};
+use aoc_2022::{Answer, Solution};
+use std::thread;
+use std::time::Duration;
+
+struct Unfinished {}
+impl Solution for Unfinished {
+    type Input = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<usize> {
+        Ok(input.parse()?)
+    }
+
+    fn compute_1(&self, _input: &usize) -> anyhow::Result<Answer> {
+        bail!("broken")
+    }
+
+    fn compute_2(&self, _input: &usize) -> anyhow::Result<Answer> {
+        todo!()
+    }
+}
+
+#[test]
+fn run_part_statuses() {
+    let outcome = runner::run_part(
+        registry::get(2022, "06").unwrap(),
+        Part::One,
+        "bvwbjplbgvbhsrlpgdmjqwftvncz",
+    );
+    assert!(matches!(outcome.status, Status::Ok(answer) if answer == "5"));
+
+    let outcome = runner::run_part(&Unfinished {}, Part::One, "1");
+    assert!(matches!(outcome.status, Status::Error(_)));
+
+    let outcome = runner::run_part(&Unfinished {}, Part::Two, "1");
+    assert!(matches!(outcome.status, Status::Unimplemented));
+}
+
+#[test]
+fn days_are_found_with_or_without_the_leading_zero() {
+    assert_eq!(registry::day_name("6").unwrap(), "06");
+    assert!(registry::get(2022, "6").is_ok());
+    assert!(registry::get(2022, "06").is_ok());
+    assert!(registry::get(2022, "26").is_err());
+}
+
+#[test]
+fn parse_failure_fails_every_part() {
+    let outcomes = runner::run_parts(&Unfinished {}, &Part::ALL, "not a number");
+
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes
+        .iter()
+        .all(|outcome| matches!(outcome.status, Status::Error(_))));
+}
+
+#[test]
+fn examples() {
+    for year in registry::all_years() {
+        for entry in year.days {
+            let (day, solution) = (entry.day(), entry.solution);
+
+            for example in solution.examples() {
+                for part in Part::ALL {
+                    if let Some(outcome) = runner::run_example(solution, part, example) {
+                        if let Status::Error(err) = outcome.status {
+                            panic!("{} day {day} part {part}: {err:#}", year.year());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn run_ordered_keeps_the_order_of_the_items() {
+    let delays = [30, 0, 20, 10, 0];
+    let mut results = vec![];
+
+    runner::run_ordered(
+        &delays,
+        3,
+        |&delay| {
+            thread::sleep(Duration::from_millis(delay));
+            delay
+        },
+        |delay| {
+            results.push(delay);
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(results, delays);
+}
+
+#[test]
+fn run_ordered_stops_when_emitting_fails() {
+    let mut emitted = 0;
+
+    let result = runner::run_ordered(
+        &[1, 2, 3],
+        1,
+        |&n| n,
+        |n| {
+            emitted += 1;
+
+            if n == 2 {
+                bail!("stop");
+            }
+
+            Ok(())
+        },
+    );
+
+    assert!(result.is_err());
+    assert_eq!(emitted, 2);
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use aoc_2022::scaffold;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = common::temp_dir(name);
+
+    fs::create_dir_all(root.join("src").join("y2022")).unwrap();
+    fs::write(
+        root.join("src").join("lib.rs"),
+        "mod y2022;\n\nregistry::years! {\n    y2022,\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("src").join("y2022").join("mod.rs"),
+        "mod day_01;\nmod day_13;\n\nregistry::days! {\n    day_01,\n    day_13,\n}\n",
+    )
+    .unwrap();
+
+    root
+}
+
+#[test]
+fn new_day_is_registered_in_order() {
+    let root = temp_root("scaffold-day");
+
+    scaffold::new_day(&root, 2022, "12").unwrap();
+
+    let module = fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap();
+    assert_eq!(
+        module,
+        "mod day_01;\nmod day_12;\nmod day_13;\n\nregistry::days! {\n    day_01,\n    day_12,\n    day_13,\n}\n"
+    );
+
+    let source = fs::read_to_string(root.join("src/y2022/day_12.rs")).unwrap();
+    assert!(source.contains("impl Solution for Day"));
+
+    let answers = fs::read_to_string(root.join("answers.toml")).unwrap();
+    assert!(answers.ends_with("\n[2022.12]\n"));
+
+    assert_eq!(fs::read_to_string(root.join("input/2022/12")).unwrap(), "");
+
+    assert!(scaffold::new_day(&root, 2022, "12").is_err());
+    assert!(scaffold::new_day(&root, 2022, "26").is_err());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn new_year_is_registered() {
+    let root = temp_root("scaffold-year");
+
+    scaffold::new_day(&root, 2023, "1").unwrap();
+
+    let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert_eq!(
+        lib,
+        "mod y2022;\nmod y2023;\n\nregistry::years! {\n    y2022,\n    y2023,\n}\n"
+    );
+
+    let module = fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap();
+    assert!(module.ends_with("mod day_01;\n\nregistry::days! {\n    day_01,\n}\n"));
+
+    let answers = fs::read_to_string(root.join("answers.toml")).unwrap();
+    assert!(answers.contains("[2023.01]"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
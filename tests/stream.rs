@@ -0,0 +1,80 @@
+use std::io::{BufReader, Cursor};
+
+use aoc_2022::generate::Rng;
+use aoc_2022::registry;
+use aoc_2022::runner::{self, Part, Status};
+
+fn describe(status: Status) -> String {
+    match status {
+        Status::Ok(answer) => answer.to_string(),
+        Status::Error(err) => format!("error: {err:#}"),
+        Status::Unimplemented => "unimplemented".to_string(),
+    }
+}
+
+#[test]
+fn streamed_answers_match() {
+    let mut streamed_days = 0;
+
+    for year in registry::all_years() {
+        for entry in year.days {
+            let solution = entry.solution;
+
+            if solution.stream_1(&mut Cursor::new("")).is_none() {
+                continue;
+            }
+
+            streamed_days += 1;
+
+            let examples = solution
+                .examples()
+                .iter()
+                .map(|example| example.input.to_string());
+            let generated = (0..10).filter_map(|seed| solution.generate(&mut Rng::new(seed), 30));
+
+            for input in examples.chain(generated) {
+                for part in Part::ALL {
+                    // A tiny buffer makes sure nothing relies on reading it
+                    // all at once
+                    let mut reader = BufReader::with_capacity(3, input.as_bytes());
+                    let streamed = runner::run_streamed(solution, part, &mut reader);
+                    let outcome = runner::run_part(solution, part, &input);
+
+                    assert_eq!(
+                        describe(streamed.status),
+                        describe(outcome.status),
+                        "Day {} part {part} on\n{input}",
+                        entry.day(),
+                    );
+                }
+            }
+        }
+    }
+
+    assert_eq!(streamed_days, 7);
+}
+
+#[test]
+fn streamed_errors_point_at_the_line() {
+    let solution = registry::get(2022, "04").unwrap();
+    let mut input = Cursor::new("2-4,6-8\n2-3,4-5\n5-x,7-9\n");
+
+    let Status::Error(err) = runner::run_streamed(solution, Part::One, &mut input).status else {
+        panic!("a broken line was accepted");
+    };
+
+    assert!(err
+        .to_string()
+        .starts_with("\"x\" is not a number at line 3, column 3"));
+}
+
+#[test]
+fn days_without_streaming_say_so() {
+    let solution = registry::get(2022, "08").unwrap();
+    let outcome = runner::run_streamed(solution, Part::Two, &mut Cursor::new("30373"));
+
+    assert_eq!(
+        describe(outcome.status),
+        "error: Part 02 cannot be solved while reading the input"
+    );
+}
@@ -0,0 +1,20 @@
+use aoc_2022::answers::Answers;
+use aoc_2022::{input, solve, Part};
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Checks a day against its answers in `answers.toml`.
+fn check(year: u16, day: &str) {
+    let answers = Answers::load(Path::new("answers.toml")).unwrap();
+    let expected = answers.get(year, day).unwrap();
+    let input = read_to_string(input::path(year, day)).unwrap();
+
+    for part in Part::ALL {
+        if let Some(answer) = expected.part(part) {
+            let result = solve(year, day, part, &input).unwrap();
+            assert_eq!(result, answer, "{year} day {day} part {part}");
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
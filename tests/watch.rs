@@ -0,0 +1,30 @@
+mod common;
+
+use aoc_2022::watch::Watcher;
+use common::temp_dir;
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn watcher_notices_changed_and_new_files() {
+    let dir = temp_dir("watch");
+    fs::create_dir_all(&dir).unwrap();
+
+    let existing = dir.join("day_07.rs");
+    let missing = dir.join("07");
+    fs::write(&existing, "").unwrap();
+
+    let mut watcher = Watcher::new([&existing, &missing]);
+    assert!(!watcher.changed());
+
+    let file = fs::File::options().write(true).open(&existing).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+    assert!(watcher.changed());
+    assert!(!watcher.changed());
+
+    fs::write(&missing, "").unwrap();
+    assert!(watcher.changed());
+
+    fs::remove_dir_all(&dir).unwrap();
+}